@@ -83,6 +83,7 @@ impl From<FromUtf8Error> for Error {
 pub enum OdsError {
     ExpectedSuperClass(&'static str),
     InvalidTrait,
+    UnexpectedVariadicRegion,
 }
 
 impl Display for OdsError {
@@ -93,6 +94,9 @@ impl Display for OdsError {
                 "expected this record to be a subclass of {class}",
             ),
             Self::InvalidTrait => write!(formatter, "record is not a supported trait"),
+            Self::UnexpectedVariadicRegion => {
+                write!(formatter, "only the last region can be variadic")
+            }
         }
     }
 }
@@ -297,6 +297,8 @@ pub struct Operation<'a> {
     pub(crate) class_name: &'a str,
     pub(crate) summary: String,
     pub(crate) can_infer_type: bool,
+    pub(crate) has_operand_segments: bool,
+    pub(crate) has_result_segments: bool,
     description: String,
     regions: Vec<OperationField<'a>>,
     successors: Vec<OperationField<'a>>,
@@ -344,15 +346,17 @@ impl<'a> Operation<'a> {
             .args()
             .enumerate()
             .map(|(index, (name, value))| {
-                OperationField::new_region(
-                    name,
-                    RegionConstraint::new(
-                        value
-                            .try_into()
-                            .map_err(|error: tblgen::Error| error.set_location(def))?,
-                    ),
-                    SequenceInfo { index, len },
-                )
+                let constraint = RegionConstraint::new(
+                    value
+                        .try_into()
+                        .map_err(|error: tblgen::Error| error.set_location(def))?,
+                );
+
+                if constraint.is_variadic() && index + 1 != len {
+                    return Err(OdsError::UnexpectedVariadicRegion.with_location(def).into());
+                }
+
+                OperationField::new_region(name, constraint, SequenceInfo { index, len })
             })
             .collect()
     }
@@ -528,10 +532,12 @@ impl<'a> Operation<'a> {
 
         let arguments = Self::dag_constraints(def, "arguments")?;
         let regions = Self::collect_regions(def)?;
+        let has_operand_segments = has_trait("::mlir::OpTrait::AttrSizedOperandSegments");
+        let has_result_segments = has_trait("::mlir::OpTrait::AttrSizedResultSegments");
         let (results, num_variable_length_results) = Self::collect_results(
             def,
             has_trait("::mlir::OpTrait::SameVariadicResultSize"),
-            has_trait("::mlir::OpTrait::AttrSizedResultSegments"),
+            has_result_segments,
         )?;
 
         let name = def.name()?;
@@ -562,7 +568,7 @@ impl<'a> Operation<'a> {
             operands: Self::collect_operands(
                 &arguments,
                 has_trait("::mlir::OpTrait::SameVariadicOperandSize"),
-                has_trait("::mlir::OpTrait::AttrSizedOperandSegments"),
+                has_operand_segments,
             )?,
             results,
             attributes: Self::collect_attributes(&arguments)?,
@@ -585,6 +591,8 @@ impl<'a> Operation<'a> {
                     )
                 }
             },
+            has_operand_segments,
+            has_result_segments,
             description: unindent::unindent(def.str_value("description")?),
             regions,
         })
@@ -2,7 +2,7 @@ use std::iter::repeat;
 
 use super::{
     super::{error::Error, utility::sanitize_snake_case_name},
-    FieldKind, Operation, OperationField,
+    ElementKind, FieldKind, Operation, OperationField, SequenceInfo,
 };
 use convert_case::{Case, Casing};
 use proc_macro2::{Ident, TokenStream};
@@ -12,17 +12,19 @@ use syn::GenericArgument;
 #[derive(Debug)]
 struct TypeStateItem {
     field_name: String,
+    sanitized_field_name: Ident,
     generic_param: GenericArgument,
 }
 
 impl TypeStateItem {
-    pub fn new(field_name: String) -> Self {
+    pub fn new(field: &OperationField) -> Self {
         Self {
             generic_param: {
-                let ident = format_ident!("__{}", field_name.to_case(Case::Snake));
+                let ident = format_ident!("__{}", field.name.to_case(Case::Snake));
                 syn::parse2(quote!(#ident)).expect("Ident is a valid GenericArgument")
             },
-            field_name,
+            field_name: field.name.into(),
+            sanitized_field_name: field.sanitized_name.clone(),
         }
     }
 }
@@ -54,9 +56,13 @@ impl TypeStateList {
     pub fn parameters_without<'a>(
         &'a self,
         field_name: &'a str,
+        set_field_names: &'a [&'a str],
     ) -> impl Iterator<Item = &GenericArgument> + '_ {
         self.items()
-            .filter(move |item| item.field_name != field_name)
+            .filter(move |item| {
+                item.field_name != field_name
+                    && !set_field_names.contains(&item.field_name.as_str())
+            })
             .map(|item| &item.generic_param)
     }
 
@@ -64,10 +70,13 @@ impl TypeStateList {
         &'a self,
         field_name: &'a str,
         argument: &'a GenericArgument,
+        set_field_names: &'a [&'a str],
     ) -> impl Iterator<Item = &GenericArgument> + '_ {
         self.items().map(move |item| {
             if item.field_name == field_name {
                 argument
+            } else if set_field_names.contains(&item.field_name.as_str()) {
+                &self.set
             } else {
                 &item.generic_param
             }
@@ -77,15 +86,17 @@ impl TypeStateList {
     pub fn arguments_set<'a>(
         &'a self,
         field_name: &'a str,
+        set_field_names: &'a [&'a str],
     ) -> impl Iterator<Item = &GenericArgument> + '_ {
-        self.arguments_replace(field_name, &self.set)
+        self.arguments_replace(field_name, &self.set, set_field_names)
     }
 
     pub fn arguments_unset<'a>(
         &'a self,
         field_name: &'a str,
+        set_field_names: &'a [&'a str],
     ) -> impl Iterator<Item = &GenericArgument> + '_ {
-        self.arguments_replace(field_name, &self.unset)
+        self.arguments_replace(field_name, &self.unset, set_field_names)
     }
 
     pub fn arguments_all_set(&self) -> impl Iterator<Item = &GenericArgument> {
@@ -116,9 +127,14 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
         phantoms: &'a [TokenStream],
     ) -> impl Iterator<Item = Result<TokenStream, Error>> + 'a {
         let builder_ident = self.builder_identifier();
+        let segment_names = self
+            .segments()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
 
         self.operation.fields().map(move |field| {
-            let name = sanitize_snake_case_name(field.name)?;
+            let name = &field.sanitized_name;
             let parameter_type = field.kind.parameter_type()?;
             let argument = quote! { #name: #parameter_type };
             let add = format_ident!("add_{}s", field.kind.as_str());
@@ -160,11 +176,14 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
                 }
             };
 
+            let update_segment = self.update_segment(field);
+
             Ok(if field.kind.is_optional()? {
                 let parameters = self.type_state.parameters().collect::<Vec<_>>();
                 quote! {
                     impl<'c, #(#parameters),*> #builder_ident<'c, #(#parameters),*> {
                         pub fn #name(mut self, #argument) -> #builder_ident<'c, #(#parameters),*> {
+                            #update_segment
                             self.builder = self.builder.#add(#add_arguments);
                             self
                         }
@@ -173,18 +192,27 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
             } else if field.kind.is_result() && self.operation.can_infer_type {
                 quote!()
             } else {
-                let parameters = self.type_state.parameters_without(field.name);
-                let arguments_set = self.type_state.arguments_set(field.name);
-                let arguments_unset = self.type_state.arguments_unset(field.name);
+                let preceding_regions = Self::preceding_regions(self.operation, field);
+                let parameters = self
+                    .type_state
+                    .parameters_without(field.name, &preceding_regions);
+                let arguments_set = self
+                    .type_state
+                    .arguments_set(field.name, &preceding_regions);
+                let arguments_unset = self
+                    .type_state
+                    .arguments_unset(field.name, &preceding_regions);
                 quote! {
                     impl<'c, #(#parameters),*> #builder_ident<'c, #(#arguments_unset),*> {
                         pub fn #name(mut self, #argument) -> #builder_ident<'c, #(#arguments_set),*> {
+                            #update_segment
                             self.builder = self.builder.#add(#add_arguments);
-                            let Self { context, mut builder, #(#field_names),* } = self;
+                            let Self { context, mut builder, #(#field_names,)* #(#segment_names,)* } = self;
                             #builder_ident {
                                 context,
                                 builder,
-                                #(#phantoms),*
+                                #(#phantoms,)*
+                                #(#segment_names,)*
                             }
                         }
                     }
@@ -197,8 +225,8 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
         let field_names = self
             .type_state
             .items()
-            .map(|field| sanitize_snake_case_name(&field.field_name))
-            .collect::<Result<Vec<_>, _>>()?;
+            .map(|item| item.sanitized_field_name.clone())
+            .collect::<Vec<_>>();
 
         let phantom_fields =
             self.type_state
@@ -219,6 +247,11 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
             .create_builder_fns(&field_names, phantom_arguments.as_slice())
             .collect::<Result<Vec<_>, _>>()?;
 
+        let segment_fields = self
+            .segments()
+            .into_iter()
+            .map(|(name, count)| quote! { #name: [i32; #count] });
+
         let new = self.create_new_fn(phantom_arguments.as_slice());
         let build = self.create_build_fn();
 
@@ -231,7 +264,8 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
             pub struct #builder_ident <'c, #(#iter_arguments),* > {
                 builder: ::melior::ir::operation::OperationBuilder<'c>,
                 context: &'c ::melior::Context,
-                #(#phantom_fields),*
+                #(#phantom_fields,)*
+                #(#segment_fields,)*
             }
 
             #new
@@ -252,11 +286,36 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
         } else {
             quote! {}
         };
+        let segment_attributes = self
+            .segments()
+            .into_iter()
+            .map(|(name, _)| {
+                let name_string = name.to_string();
+
+                quote! {
+                    .add_attributes(&[(
+                        ::melior::ir::Identifier::new(self.context, #name_string),
+                        ::melior::ir::attribute::DenseI32ArrayAttribute::new(
+                            self.context,
+                            &self.#name,
+                        )
+                        .into(),
+                    )])
+                }
+            })
+            .collect::<Vec<_>>();
 
         quote! {
             impl<'c> #builder_ident<'c, #(#arguments_set),*> {
                 pub fn build(self) -> #class_name<'c> {
-                    self.builder #maybe_infer.build().try_into().expect(#error)
+                    self.builder #(#segment_attributes)* #maybe_infer.build().try_into().expect(#error)
+                }
+
+                /// Builds an operation without checking it through `TryFrom`.
+                pub fn build_unchecked(self) -> #class_name<'c> {
+                    #class_name {
+                        operation: self.builder #(#segment_attributes)* #maybe_infer.build_unverified(),
+                    }
                 }
             }
         }
@@ -266,6 +325,10 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
         let builder_ident = self.builder_identifier();
         let name = &self.operation.full_name;
         let arguments_unset = self.type_state.arguments_all_unset();
+        let segments = self
+            .segments()
+            .into_iter()
+            .map(|(name, count)| quote! { #name: [0; #count] });
 
         quote! {
             impl<'c> #builder_ident<'c, #(#arguments_unset),*> {
@@ -273,7 +336,8 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
                     Self {
                         context: unsafe { location.context().to_ref() },
                         builder: ::melior::ir::operation::OperationBuilder::new(#name, location),
-                        #(#phantoms),*
+                        #(#phantoms,)*
+                        #(#segments,)*
                     }
                 }
             }
@@ -295,7 +359,14 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
     pub fn create_default_constructor(&self) -> Result<TokenStream, Error> {
         let class_name = format_ident!("{}", &self.operation.class_name);
         let name = sanitize_snake_case_name(self.operation.short_name)?;
-        let arguments = Self::required_fields(self.operation)
+        let region_result = Self::region_result(self.operation);
+        let fields =
+            Self::required_fields(self.operation).filter(|field| match (field, region_result) {
+                (Ok(field), Some(result)) => !std::ptr::eq(*field, result),
+                _ => true,
+            });
+        let arguments = fields
+            .clone()
             .map(|field| {
                 let field = field?;
                 let parameter_type = &field.kind.parameter_type()?;
@@ -303,14 +374,20 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
 
                 Ok(quote! { #parameter_name: #parameter_type })
             })
+            .chain(region_result.map(|_| Ok(quote! { result_types: &[::melior::ir::Type<'c>] })))
             .chain([Ok(quote! { location: ::melior::ir::Location<'c> })])
             .collect::<Result<Vec<_>, Error>>()?;
-        let builder_calls = Self::required_fields(self.operation)
+        let builder_calls = fields
             .map(|field| {
                 let parameter_name = &field?.sanitized_name;
 
                 Ok(quote! { .#parameter_name(#parameter_name) })
             })
+            .chain(region_result.map(|field| {
+                let name = &field.sanitized_name;
+
+                Ok(quote! { .#name(result_types) })
+            }))
             .collect::<Result<Vec<_>, Error>>()?;
 
         let doc = format!("Creates a new {}", self.operation.summary);
@@ -324,9 +401,110 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
         })
     }
 
+    // Results of operations with regions (e.g. `scf.if`) are typically determined
+    // by their region terminators, so they are passed last as `result_types`.
+    fn region_result<'a, 'b>(operation: &'a Operation<'b>) -> Option<&'a OperationField<'b>> {
+        if operation.can_infer_type || operation.regions.is_empty() {
+            return None;
+        }
+
+        let mut results = operation.fields().filter(|field| field.kind.is_result());
+
+        match (results.next(), results.next()) {
+            (
+                Some(
+                    field @ OperationField {
+                        kind: FieldKind::Element { constraint, .. },
+                        ..
+                    },
+                ),
+                None,
+            ) if constraint.is_variadic() => Some(field),
+            _ => None,
+        }
+    }
+
+    // Operations with the `AttrSized{Operand,Result}Segments` traits need sizes of
+    // their element groups. They are recorded by builder functions and added as
+    // an attribute on build. Inferred results have no builder functions.
+    fn segments(&self) -> Vec<(Ident, usize)> {
+        let mut segments = vec![];
+
+        if self.operation.has_operand_segments {
+            segments.push((
+                format_ident!("operand_segment_sizes"),
+                self.operation.operands.len(),
+            ));
+        }
+
+        if self.operation.has_result_segments && !self.operation.can_infer_type {
+            segments.push((
+                format_ident!("result_segment_sizes"),
+                self.operation.results.len(),
+            ));
+        }
+
+        segments
+    }
+
+    fn update_segment(&self, field: &OperationField) -> TokenStream {
+        let FieldKind::Element {
+            kind,
+            constraint,
+            sequence_info: SequenceInfo { index, .. },
+            ..
+        } = &field.kind
+        else {
+            return quote!();
+        };
+        let enabled = match kind {
+            ElementKind::Operand => self.operation.has_operand_segments,
+            ElementKind::Result => {
+                self.operation.has_result_segments && !self.operation.can_infer_type
+            }
+        };
+
+        if !enabled {
+            return quote!();
+        }
+
+        let segments = format_ident!("{}_segment_sizes", kind.as_str());
+        let name = &field.sanitized_name;
+        let size = if constraint.has_variable_length() && !constraint.is_optional() {
+            quote! { #name.len() as i32 }
+        } else {
+            quote! { 1 }
+        };
+
+        quote! { self.#segments[#index] = #size; }
+    }
+
+    // Regions are added to operations in the order their builder functions are
+    // called, so a region can be set only after all of its preceding regions are
+    // set. Only the last region can be variadic, so no segment sizes are needed.
+    fn preceding_regions<'a>(
+        operation: &Operation<'a>,
+        field: &OperationField<'a>,
+    ) -> Vec<&'a str> {
+        let FieldKind::Region {
+            sequence_info: SequenceInfo { index, .. },
+            ..
+        } = field.kind
+        else {
+            return vec![];
+        };
+
+        operation
+            .regions
+            .iter()
+            .take(index)
+            .map(|region| region.name)
+            .collect()
+    }
+
     fn required_fields<'a, 'b>(
         operation: &'a Operation<'b>,
-    ) -> impl Iterator<Item = Result<&'a OperationField<'b>, Error>> {
+    ) -> impl Iterator<Item = Result<&'a OperationField<'b>, Error>> + Clone {
         operation
             .fields()
             .filter(|field| !field.kind.is_result() || !operation.can_infer_type)
@@ -339,7 +517,7 @@ impl<'o, 'c> OperationBuilder<'o, 'c> {
     fn create_type_state(operation: &'c Operation<'o>) -> Result<TypeStateList, Error> {
         Ok(TypeStateList::new(
             Self::required_fields(operation)
-                .map(|field| Ok(TypeStateItem::new(field?.name.to_string())))
+                .map(|field| Ok(TypeStateItem::new(field?)))
                 .collect::<Result<_, Error>>()?,
         ))
     }
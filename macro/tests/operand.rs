@@ -54,3 +54,70 @@ fn variadic_after_single() {
     assert_eq!(op.operation().operand_count(), 3);
     assert_eq!(op.others().count(), 2);
 }
+
+#[test]
+fn attr_sized() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location); 4]);
+    let arguments = (0..4)
+        .map(|index| block.argument(index).unwrap().into())
+        .collect::<Vec<_>>();
+    let op = operand_test::attr_sized(&arguments[0..2], arguments[2], &arguments[3..], location);
+
+    assert_eq!(op.first().unwrap().collect::<Vec<_>>(), &arguments[0..2]);
+    assert_eq!(op.second().unwrap(), arguments[2]);
+    assert_eq!(op.third().unwrap().collect::<Vec<_>>(), &arguments[3..]);
+    assert_eq!(
+        op.operation()
+            .attribute("operand_segment_sizes")
+            .unwrap()
+            .to_string(),
+        "array<i32: 2, 1, 1>"
+    );
+}
+
+#[test]
+fn keyword_name() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location)]);
+    let op = operand_test::keyword(block.argument(0).unwrap().into(), location);
+    let op2 = operand_test::KeywordOp::builder(location)
+        .r#type(block.argument(0).unwrap().into())
+        .build();
+
+    assert_eq!(op.operation().to_string(), op2.operation().to_string());
+    assert_eq!(op.r#type().unwrap(), block.argument(0).unwrap().into());
+}
+
+#[test]
+fn build_unchecked() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+
+    let r#type = Type::parse(&context, "i32").unwrap();
+    let block = Block::new(&[(r#type, location), (r#type, location)]);
+    let op = operand_test::SimpleOp::builder(location)
+        .res(r#type)
+        .lhs(block.argument(0).unwrap().into())
+        .rhs(block.argument(1).unwrap().into())
+        .build();
+    let op2 = operand_test::SimpleOp::builder(location)
+        .res(r#type)
+        .lhs(block.argument(0).unwrap().into())
+        .rhs(block.argument(1).unwrap().into())
+        .build_unchecked();
+
+    assert_eq!(op.operation().to_string(), op2.operation().to_string());
+}
@@ -1,6 +1,6 @@
 mod utility;
 
-use melior::ir::{Block, Location, Region};
+use melior::ir::{Block, Location, Region, Type, ValueLike};
 use utility::*;
 
 melior_macro::dialect! {
@@ -56,3 +56,55 @@ fn variadic_after_single() {
     assert!(op.other_regions().next().unwrap().first_block().is_some());
     assert!(op.other_regions().nth(1).unwrap().first_block().is_none());
 }
+
+#[test]
+fn variadic_region_order() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+    let index_type = Type::index(&context);
+
+    let create_region = |argument_count| {
+        let region = Region::new();
+        region.append_block(Block::new(&vec![(index_type, location); argument_count]));
+        region
+    };
+
+    let op = region_test::VariadicOp::builder(location)
+        .default_region(create_region(0))
+        .other_regions(vec![create_region(1), create_region(2)])
+        .build();
+
+    assert_eq!(
+        op.operation()
+            .regions()
+            .map(|region| region.first_block().unwrap().argument_count())
+            .collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(
+        op.other_regions()
+            .map(|region| region.first_block().unwrap().argument_count())
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn region_results() {
+    let context = create_test_context();
+    context.set_allow_unregistered_dialects(true);
+
+    let location = Location::unknown(&context);
+    let index_type = Type::index(&context);
+
+    let op = {
+        let region = Region::new();
+        region.append_block(Block::new(&[]));
+        region_test::result(region, &[index_type, index_type], location)
+    };
+
+    assert_eq!(op.results().count(), 2);
+    assert_eq!(op.results().next().unwrap().r#type(), index_type);
+}
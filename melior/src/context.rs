@@ -1,9 +1,12 @@
 use crate::{
-    diagnostic::{Diagnostic, DiagnosticHandlerId},
+    diagnostic::{CapturedDiagnostic, Diagnostic, DiagnosticHandlerId},
     dialect::{Dialect, DialectRegistry},
+    ir::{Attribute, Identifier, Type},
     logical_result::LogicalResult,
     string_ref::StringRef,
+    Error,
 };
+use dashmap::DashMap;
 use mlir_sys::{
     mlirContextAppendDialectRegistry, mlirContextAttachDiagnosticHandler, mlirContextCreate,
     mlirContextDestroy, mlirContextDetachDiagnosticHandler, mlirContextEnableMultithreading,
@@ -12,7 +15,14 @@ use mlir_sys::{
     mlirContextIsRegisteredOperation, mlirContextLoadAllAvailableDialects,
     mlirContextSetAllowUnregisteredDialects, MlirContext, MlirDiagnostic, MlirLogicalResult,
 };
-use std::{ffi::c_void, marker::PhantomData, mem::transmute, ops::Deref};
+use once_cell::sync::Lazy;
+use std::{cell::RefCell, ffi::c_void, marker::PhantomData, mem::transmute, ops::Deref};
+
+// Diagnostic handlers are tracked for each underlying context, so that handlers
+// attached through any context references can be detached together. Entries
+// are removed when contexts are dropped.
+static DIAGNOSTIC_HANDLERS: Lazy<DashMap<usize, Vec<DiagnosticHandlerId>>> =
+    Lazy::new(Default::default);
 
 /// A context of IR, dialects, and passes.
 ///
@@ -26,9 +36,20 @@ pub struct Context {
 impl Context {
     /// Creates a context.
     pub fn new() -> Self {
-        Self {
-            raw: unsafe { mlirContextCreate() },
-        }
+        unsafe { Self::from_raw(mlirContextCreate()) }
+    }
+
+    /// Creates a context with dialects in a registry.
+    ///
+    /// No dialects other than the builtin one are loaded until they are
+    /// requested.
+    pub fn new_with_registry(registry: &DialectRegistry, threading_enabled: bool) -> Self {
+        let context = Self::new();
+
+        context.append_dialect_registry(registry);
+        context.enable_multi_threading(threading_enabled);
+
+        context
     }
 
     /// Gets a number of registered dialects.
@@ -81,6 +102,46 @@ impl Context {
         unsafe { mlirContextIsRegisteredOperation(self.raw, StringRef::from(name).to_raw()) }
     }
 
+    /// Gets an identifier interned in a context.
+    ///
+    /// This is equivalent to [`Identifier::new`]. Identifiers are already
+    /// interned by contexts, so they are not cached on the Rust side.
+    pub fn identifier(&self, name: &str) -> Identifier {
+        Identifier::new(self, name)
+    }
+
+    /// Parses a type.
+    ///
+    /// Diagnostics emitted on failure are captured into an error.
+    pub fn parse_type(&self, source: &str) -> Result<Type, Error> {
+        Type::parse(self, source)
+    }
+
+    /// Parses an attribute.
+    ///
+    /// Diagnostics emitted on failure are captured into an error.
+    pub fn parse_attribute(&self, source: &str) -> Result<Attribute, Error> {
+        Attribute::parse(self, source)
+    }
+
+    unsafe fn from_raw(raw: MlirContext) -> Self {
+        Self { raw }
+    }
+
+    /// Creates a context reference from a raw object borrowed from other
+    /// code, such as C++ code.
+    ///
+    /// Unlike [`Context`], the returned reference never destroys the context
+    /// on drop.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid during the lifetime of the returned
+    /// reference.
+    pub unsafe fn from_raw_borrowed<'a>(raw: MlirContext) -> ContextRef<'a> {
+        ContextRef::from_raw(raw)
+    }
+
     /// Converts a context into a raw object.
     pub const fn to_raw(&self) -> MlirContext {
         self.raw
@@ -102,24 +163,69 @@ impl Context {
             drop(Box::from_raw(user_data as *mut F));
         }
 
-        unsafe {
+        let id = unsafe {
             DiagnosticHandlerId::from_raw(mlirContextAttachDiagnosticHandler(
                 self.to_raw(),
                 Some(handle::<F>),
                 Box::into_raw(Box::new(handler)) as *mut _,
                 Some(destroy::<F>),
             ))
-        }
+        };
+
+        DIAGNOSTIC_HANDLERS
+            .entry(self.raw.ptr as usize)
+            .or_default()
+            .push(id);
+
+        id
     }
 
     /// Detaches a diagnostic handler.
     pub fn detach_diagnostic_handler(&self, id: DiagnosticHandlerId) {
+        if let Some(mut ids) = DIAGNOSTIC_HANDLERS.get_mut(&(self.raw.ptr as usize)) {
+            ids.retain(|other| other.to_raw() != id.to_raw());
+        }
+
         unsafe { mlirContextDetachDiagnosticHandler(self.to_raw(), id.to_raw()) }
     }
+
+    /// Detaches all diagnostic handlers attached through this context or any
+    /// references to it.
+    ///
+    /// Handlers attached by other code, such as C++ code, are not detached.
+    pub fn detach_all_diagnostic_handlers(&self) {
+        if let Some((_, ids)) = DIAGNOSTIC_HANDLERS.remove(&(self.raw.ptr as usize)) {
+            for id in ids {
+                unsafe { mlirContextDetachDiagnosticHandler(self.to_raw(), id.to_raw()) }
+            }
+        }
+    }
+
+    /// Runs a function capturing all diagnostics emitted during it.
+    ///
+    /// Captured diagnostics are not propagated to other diagnostic handlers.
+    pub fn with_captured_diagnostics<T>(
+        &self,
+        function: impl FnOnce() -> T,
+    ) -> (T, Vec<CapturedDiagnostic>) {
+        let diagnostics = RefCell::new(vec![]);
+        let id = self.attach_diagnostic_handler(|diagnostic| {
+            diagnostics.borrow_mut().push(diagnostic.into());
+            true
+        });
+
+        let value = function();
+
+        self.detach_diagnostic_handler(id);
+
+        (value, diagnostics.into_inner())
+    }
 }
 
 impl Drop for Context {
     fn drop(&mut self) {
+        DIAGNOSTIC_HANDLERS.remove(&(self.raw.ptr as usize));
+
         unsafe { mlirContextDestroy(self.raw) };
     }
 }
@@ -194,12 +300,38 @@ impl<'a> Eq for ContextRef<'a> {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        diagnostic::DiagnosticSeverity,
+        ir::{operation::OperationBuilder, Location},
+        test::create_test_context,
+        utility::register_all_dialects,
+    };
+    use std::cell::Cell;
 
     #[test]
     fn new() {
         Context::new();
     }
 
+    #[test]
+    fn new_with_registry() {
+        let context = Context::new_with_registry(&DialectRegistry::new(), false);
+
+        assert_eq!(context.registered_dialect_count(), 1);
+        assert_eq!(context.loaded_dialect_count(), 1);
+    }
+
+    #[test]
+    fn new_with_registry_without_loading() {
+        let registry = DialectRegistry::new();
+        register_all_dialects(&registry);
+
+        let context = Context::new_with_registry(&registry, true);
+
+        assert!(context.registered_dialect_count() > 1);
+        assert_eq!(context.loaded_dialect_count(), 1);
+    }
+
     #[test]
     fn registered_dialect_count() {
         let context = Context::new();
@@ -235,6 +367,75 @@ mod tests {
         assert!(!context.is_registered_operation("func.func"));
     }
 
+    #[test]
+    fn from_raw_borrowed() {
+        let context = Context::new();
+
+        {
+            let borrowed = unsafe { Context::from_raw_borrowed(context.to_raw()) };
+
+            assert_eq!(&*borrowed, &context);
+            assert_eq!(borrowed.parse_type("index"), Ok(Type::index(&context)));
+        }
+
+        assert_eq!(context.parse_type("index"), Ok(Type::index(&context)));
+    }
+
+    #[test]
+    fn parse_type() {
+        let context = Context::new();
+
+        assert_eq!(context.parse_type("index"), Ok(Type::index(&context)));
+    }
+
+    #[test]
+    fn parse_type_with_error() {
+        let context = Context::new();
+
+        assert_eq!(
+            context.parse_type("z"),
+            Err(Error::ParseType("expected non-function type".into()))
+        );
+    }
+
+    #[test]
+    fn parse_attribute() {
+        let context = Context::new();
+
+        assert_eq!(
+            context.parse_attribute("unit"),
+            Ok(Attribute::unit(&context))
+        );
+    }
+
+    #[test]
+    fn parse_attribute_with_error() {
+        let context = Context::new();
+
+        assert_eq!(
+            context.parse_attribute("z"),
+            Err(Error::ParseAttribute("expected attribute value".into()))
+        );
+    }
+
+    #[test]
+    fn identifier() {
+        let context = Context::new();
+
+        assert_eq!(context.identifier("foo"), context.identifier("foo"));
+        assert_eq!(context.identifier("foo"), Identifier::new(&context, "foo"));
+        assert_ne!(context.identifier("foo"), context.identifier("bar"));
+    }
+
+    #[test]
+    fn identifier_in_different_contexts() {
+        let context = Context::new();
+        let other_context = Context::new();
+
+        assert_eq!(&*context.identifier("foo").context(), &context);
+        assert_eq!(&*other_context.identifier("foo").context(), &other_context);
+    }
+
     #[test]
     fn enable_multi_threading() {
         let context = Context::new();
@@ -276,4 +477,73 @@ mod tests {
 
         context.detach_diagnostic_handler(id);
     }
+
+    #[test]
+    fn detach_all_diagnostic_handlers() {
+        let context = Context::new();
+        let location = Location::unknown(&context);
+        let count = Cell::new(0);
+
+        for _ in 0..2 {
+            context.attach_diagnostic_handler(|_| {
+                count.set(count.get() + 1);
+                false
+            });
+        }
+
+        location.emit_error("foo");
+        assert_eq!(count.get(), 2);
+
+        context.detach_all_diagnostic_handlers();
+        location.emit_error("bar");
+
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn detach_all_diagnostic_handlers_attached_through_reference() {
+        let context = Context::new();
+        let location = Location::unknown(&context);
+        let count = Cell::new(0);
+
+        location.context().attach_diagnostic_handler(|_| {
+            count.set(count.get() + 1);
+            false
+        });
+
+        location.emit_error("foo");
+        assert_eq!(count.get(), 1);
+
+        context.detach_all_diagnostic_handlers();
+        location.emit_error("bar");
+
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn with_captured_diagnostics() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+
+        let (verified, diagnostics) = context.with_captured_diagnostics(|| {
+            OperationBuilder::new("arith.addi", location)
+                .build()
+                .verify()
+        });
+
+        assert!(!verified);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert!(diagnostics[0].message().starts_with("'arith.addi' op"));
+    }
+
+    #[test]
+    fn with_captured_diagnostics_without_diagnostics() {
+        let context = Context::new();
+
+        let (value, diagnostics) = context.with_captured_diagnostics(|| 42);
+
+        assert_eq!(value, 42);
+        assert!(diagnostics.is_empty());
+    }
 }
@@ -1,9 +1,12 @@
 //! Diagnostics.
 
+mod captured;
 mod handler_id;
 mod severity;
 
-pub use self::{handler_id::DiagnosticHandlerId, severity::DiagnosticSeverity};
+pub use self::{
+    captured::CapturedDiagnostic, handler_id::DiagnosticHandlerId, severity::DiagnosticSeverity,
+};
 use crate::{ir::Location, utility::print_callback, Error};
 use mlir_sys::{
     mlirDiagnosticGetLocation, mlirDiagnosticGetNote, mlirDiagnosticGetNumNotes,
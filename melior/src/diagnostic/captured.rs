@@ -0,0 +1,47 @@
+use super::{Diagnostic, DiagnosticSeverity};
+
+/// A diagnostic captured out of its handler.
+///
+/// Unlike [`Diagnostic`], it owns its contents and outlives a handler call.
+#[derive(Clone, Debug)]
+pub struct CapturedDiagnostic {
+    severity: DiagnosticSeverity,
+    location: String,
+    message: String,
+    notes: Vec<CapturedDiagnostic>,
+}
+
+impl CapturedDiagnostic {
+    /// Gets a severity.
+    pub fn severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+
+    /// Gets a location.
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Gets a message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets notes.
+    pub fn notes(&self) -> &[CapturedDiagnostic] {
+        &self.notes
+    }
+}
+
+impl<'c> From<Diagnostic<'c>> for CapturedDiagnostic {
+    fn from(diagnostic: Diagnostic<'c>) -> Self {
+        Self {
+            severity: diagnostic.severity(),
+            location: diagnostic.location().to_string(),
+            message: diagnostic.to_string(),
+            notes: (0..diagnostic.note_count())
+                .map(|index| diagnostic.note(index).expect("valid note index").into())
+                .collect(),
+        }
+    }
+}
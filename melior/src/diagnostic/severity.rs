@@ -5,7 +5,7 @@ use mlir_sys::{
 };
 
 /// Diagnostic severity.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum DiagnosticSeverity {
     Error,
     Note,
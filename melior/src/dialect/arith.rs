@@ -89,7 +89,7 @@ fn cmp<'c>(
     OperationBuilder::new(name, location)
         .add_attributes(&[(
             Identifier::new(context, "predicate"),
-            IntegerAttribute::new(predicate, IntegerType::new(context, 64).into()).into(),
+            IntegerAttribute::new_wrapping(predicate, IntegerType::new(context, 64).into()).into(),
         )])
         .add_operands(&[lhs, rhs])
         .enable_result_type_inference()
@@ -97,8 +97,8 @@ pub fn switch<'c>(
                     RankedTensorType::new(&[case_values.len() as u64], flag_type, None).into(),
                     &case_values
                         .iter()
-                        .map(|value| IntegerAttribute::new(*value, flag_type).into())
-                        .collect::<Vec<_>>(),
+                        .map(|value| Ok(IntegerAttribute::new(*value, flag_type)?.into()))
+                        .collect::<Result<Vec<_>, Error>>()?,
                 )?
                 .into(),
             ),
@@ -174,7 +174,7 @@ mod tests {
                 let operand = block
                     .append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(1, bool_type).into(),
+                        IntegerAttribute::new(1, bool_type).unwrap().into(),
                         location,
                     ))
                     .result(0)
@@ -197,6 +197,23 @@ mod tests {
         insta::assert_display_snapshot!(module.as_operation());
     }
 
+    #[test]
+    fn br_successor() {
+        let context = Context::new();
+        load_all_dialects(&context);
+
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        let dest_block = region.append_block(Block::new(&[]));
+
+        let operation = block.append_operation(br(&dest_block, &[], location));
+
+        assert_eq!(operation.successor_count(), 1);
+        assert_eq!(operation.successor(0).unwrap(), dest_block);
+        assert!(operation.successor(1).is_err());
+    }
+
     #[test]
     fn compile_br() {
         let context = Context::new();
@@ -216,7 +233,7 @@ mod tests {
                 let operand = block
                     .append_operation(index::constant(
                         &context,
-                        IntegerAttribute::new(1, index_type),
+                        IntegerAttribute::new(1, index_type).unwrap(),
                         location,
                     ))
                     .result(0)
@@ -260,7 +277,7 @@ mod tests {
                 let operand = block
                     .append_operation(index::constant(
                         &context,
-                        IntegerAttribute::new(1, index_type),
+                        IntegerAttribute::new(1, index_type).unwrap(),
                         location,
                     ))
                     .result(0)
@@ -328,7 +345,7 @@ mod tests {
                 let operand = block
                     .append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(1, i32_type).into(),
+                        IntegerAttribute::new(1, i32_type).unwrap().into(),
                         location,
                     ))
                     .result(0)
@@ -137,7 +137,7 @@ mod tests {
             |_| {
                 constant(
                     &context,
-                    IntegerAttribute::new(42, index_type),
+                    IntegerAttribute::new(42, index_type).unwrap(),
                     Location::unknown(&context),
                 )
             },
@@ -362,7 +362,7 @@ mod tests {
                 let index = block
                     .append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(42, integer_type).into(),
+                        IntegerAttribute::new(42, integer_type).unwrap().into(),
                         location,
                     ))
                     .result(0)
@@ -412,7 +412,7 @@ mod tests {
                 let value = block
                     .append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(42, integer_type).into(),
+                        IntegerAttribute::new(42, integer_type).unwrap().into(),
                         location,
                     ))
                     .result(0)
@@ -653,7 +653,7 @@ mod tests {
                     block.argument(0).unwrap().into(),
                     location,
                     LoadStoreOptions::new()
-                        .align(Some(IntegerAttribute::new(4, integer_type)))
+                        .align(Some(IntegerAttribute::new(4, integer_type).unwrap()))
                         .volatile(true)
                         .nontemporal(true),
                 ));
@@ -379,7 +379,7 @@ mod tests {
 
             let index = block.append_operation(index::constant(
                 &context,
-                IntegerAttribute::new(0, Type::index(&context)),
+                IntegerAttribute::new(0, Type::index(&context)).unwrap(),
                 location,
             ));
 
@@ -467,16 +467,13 @@ mod tests {
             Some(
                 DenseElementsAttribute::new(
                     RankedTensorType::new(&[], r#type, None).into(),
-                    &[IntegerAttribute::new(42, r#type).into()],
+                    &[IntegerAttribute::new(42, r#type).unwrap().into()],
                 )
                 .unwrap()
                 .into(),
             ),
             true,
-            Some(IntegerAttribute::new(
-                8,
-                IntegerType::new(&context, 64).into(),
-            )),
+            Some(IntegerAttribute::new(8, IntegerType::new(&context, 64).into()).unwrap()),
             location,
         ));
 
@@ -519,7 +516,7 @@ mod tests {
 
             let index = block.append_operation(index::constant(
                 &context,
-                IntegerAttribute::new(0, Type::index(&context)),
+                IntegerAttribute::new(0, Type::index(&context)).unwrap(),
                 location,
             ));
 
@@ -566,7 +563,7 @@ mod tests {
 
             let value = block.append_operation(index::constant(
                 &context,
-                IntegerAttribute::new(42, Type::index(&context)),
+                IntegerAttribute::new(42, Type::index(&context)).unwrap(),
                 location,
             ));
 
@@ -596,13 +593,13 @@ mod tests {
 
             let value = block.append_operation(index::constant(
                 &context,
-                IntegerAttribute::new(42, Type::index(&context)),
+                IntegerAttribute::new(42, Type::index(&context)).unwrap(),
                 location,
             ));
 
             let index = block.append_operation(index::constant(
                 &context,
-                IntegerAttribute::new(0, Type::index(&context)),
+                IntegerAttribute::new(0, Type::index(&context)).unwrap(),
                 location,
             ));
 
@@ -102,3 +102,39 @@ melior_macro::dialect! {
     name: "vector",
     tablegen: r#"include "mlir/Dialect/Vector/IR/VectorOps.td""#
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            attribute::{StringAttribute, TypeAttribute},
+            r#type::FunctionType,
+            Block, Location, Region,
+        },
+        test::create_test_context,
+    };
+
+    #[test]
+    fn func_function_type() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let r#type = FunctionType::new(&context, &[], &[]);
+
+        let function = func::FuncOp::builder(location)
+            .sym_name(StringAttribute::new(&context, "foo"))
+            .function_type(TypeAttribute::new(r#type.into()))
+            .body({
+                let block = Block::new(&[]);
+                block.append_operation(func::r#return(&[], location).into());
+
+                let region = Region::new();
+                region.append_block(block);
+                region
+            })
+            .build();
+
+        assert_eq!(function.function_type().unwrap().value(), r#type.into());
+        assert!(function.operation().verify());
+    }
+}
@@ -137,7 +137,7 @@ mod tests {
 
                         let value = block.append_operation(arith::constant(
                             &context,
-                            IntegerAttribute::new(0, index_type).into(),
+                            IntegerAttribute::new(0, index_type).unwrap().into(),
                             location,
                         ));
 
@@ -250,7 +250,9 @@ mod tests {
 
                     let condition = block.append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(0, IntegerType::new(&context, 1).into()).into(),
+                        IntegerAttribute::new(0, IntegerType::new(&context, 1).into())
+                            .unwrap()
+                            .into(),
                         location,
                     ));
 
@@ -262,7 +264,7 @@ mod tests {
 
                             let result = block.append_operation(arith::constant(
                                 &context,
-                                IntegerAttribute::new(42, index_type).into(),
+                                IntegerAttribute::new(42, index_type).unwrap().into(),
                                 location,
                             ));
 
@@ -280,7 +282,7 @@ mod tests {
 
                             let result = block.append_operation(arith::constant(
                                 &context,
-                                IntegerAttribute::new(13, index_type).into(),
+                                IntegerAttribute::new(13, index_type).unwrap().into(),
                                 location,
                             ));
 
@@ -330,7 +332,9 @@ mod tests {
 
                     let condition = block.append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(0, IntegerType::new(&context, 1).into()).into(),
+                        IntegerAttribute::new(0, IntegerType::new(&context, 1).into())
+                            .unwrap()
+                            .into(),
                         location,
                     ));
 
@@ -382,7 +386,9 @@ mod tests {
 
                 let condition = block.append_operation(arith::constant(
                     &context,
-                    IntegerAttribute::new(0, Type::index(&context)).into(),
+                    IntegerAttribute::new(0, Type::index(&context))
+                        .unwrap()
+                        .into(),
                     location,
                 ));
 
@@ -458,7 +464,7 @@ mod tests {
 
                     let initial = block.append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(0, index_type).into(),
+                        IntegerAttribute::new(0, index_type).unwrap().into(),
                         location,
                     ));
 
@@ -471,13 +477,16 @@ mod tests {
                             let condition = block.append_operation(arith::constant(
                                 &context,
                                 IntegerAttribute::new(0, IntegerType::new(&context, 1).into())
+                                    .unwrap()
                                     .into(),
                                 location,
                             ));
 
                             let result = block.append_operation(arith::constant(
                                 &context,
-                                IntegerAttribute::new(42, Type::index(&context)).into(),
+                                IntegerAttribute::new(42, Type::index(&context))
+                                    .unwrap()
+                                    .into(),
                                 location,
                             ));
 
@@ -496,7 +505,7 @@ mod tests {
 
                             let result = block.append_operation(arith::constant(
                                 &context,
-                                IntegerAttribute::new(42, index_type).into(),
+                                IntegerAttribute::new(42, index_type).unwrap().into(),
                                 location,
                             ));
 
@@ -545,7 +554,7 @@ mod tests {
 
                     let initial = block.append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(0, index_type).into(),
+                        IntegerAttribute::new(0, index_type).unwrap().into(),
                         location,
                     ));
 
@@ -558,6 +567,7 @@ mod tests {
                             let condition = block.append_operation(arith::constant(
                                 &context,
                                 IntegerAttribute::new(0, IntegerType::new(&context, 1).into())
+                                    .unwrap()
                                     .into(),
                                 location,
                             ));
@@ -583,7 +593,9 @@ mod tests {
 
                             let result = block.append_operation(arith::constant(
                                 &context,
-                                IntegerAttribute::new(42, Type::index(&context)).into(),
+                                IntegerAttribute::new(42, Type::index(&context))
+                                    .unwrap()
+                                    .into(),
                                 location,
                             ));
 
@@ -631,7 +643,7 @@ mod tests {
 
                     let initial = block.append_operation(arith::constant(
                         &context,
-                        IntegerAttribute::new(0, index_type).into(),
+                        IntegerAttribute::new(0, index_type).unwrap().into(),
                         location,
                     ));
 
@@ -648,13 +660,16 @@ mod tests {
                             let condition = block.append_operation(arith::constant(
                                 &context,
                                 IntegerAttribute::new(0, IntegerType::new(&context, 1).into())
+                                    .unwrap()
                                     .into(),
                                 location,
                             ));
 
                             let result = block.append_operation(arith::constant(
                                 &context,
-                                IntegerAttribute::new(42, Type::index(&context)).into(),
+                                IntegerAttribute::new(42, Type::index(&context))
+                                    .unwrap()
+                                    .into(),
                                 location,
                             ));
 
@@ -677,7 +692,7 @@ mod tests {
 
                             let result = block.append_operation(arith::constant(
                                 &context,
-                                IntegerAttribute::new(42, index_type).into(),
+                                IntegerAttribute::new(42, index_type).unwrap().into(),
                                 location,
                             ));
 
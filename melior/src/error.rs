@@ -11,10 +11,15 @@ pub enum Error {
     AttributeExpected(&'static str, String),
     AttributeNotFound(String),
     BlockArgumentExpected(String),
+    DuplicateSymbol(String),
     ElementExpected {
         r#type: &'static str,
         value: String,
     },
+    IntegerOutOfRange {
+        integer: i64,
+        r#type: String,
+    },
     InvokeFunction,
     OperandNotFound(&'static str),
     OperationResultExpected(String),
@@ -23,9 +28,21 @@ pub enum Error {
         value: String,
         index: usize,
     },
+    ParseAttribute(String),
     ParsePassPipeline(String),
+    ParseType(String),
+    ResultCountExpected {
+        expected: usize,
+        count: usize,
+        operation: String,
+    },
     ResultNotFound(&'static str),
+    ResultTypeInference(String),
     RunPass,
+    SingleResultExpected {
+        count: usize,
+        operation: String,
+    },
     TypeExpected(&'static str, String),
     UnknownDiagnosticSeverity(u32),
     Utf8(Utf8Error),
@@ -43,9 +60,15 @@ impl Display for Error {
             Self::BlockArgumentExpected(value) => {
                 write!(formatter, "block argument expected: {value}")
             }
+            Self::DuplicateSymbol(name) => {
+                write!(formatter, "duplicate symbol: {name}")
+            }
             Self::ElementExpected { r#type, value } => {
                 write!(formatter, "element of {type} type expected: {value}")
             }
+            Self::IntegerOutOfRange { integer, r#type } => {
+                write!(formatter, "integer {integer} out of range of type: {type}")
+            }
             Self::InvokeFunction => write!(formatter, "failed to invoke JIT-compiled function"),
             Self::OperandNotFound(name) => {
                 write!(formatter, "operand {name} not found")
@@ -53,16 +76,44 @@ impl Display for Error {
             Self::OperationResultExpected(value) => {
                 write!(formatter, "operation result expected: {value}")
             }
+            Self::ParseAttribute(message) => {
+                write!(formatter, "failed to parse attribute:\n{}", message)
+            }
             Self::ParsePassPipeline(message) => {
                 write!(formatter, "failed to parse pass pipeline:\n{}", message)
             }
+            Self::ParseType(message) => {
+                write!(formatter, "failed to parse type:\n{}", message)
+            }
             Self::PositionOutOfBounds { name, value, index } => {
                 write!(formatter, "{name} position {index} out of bounds: {value}")
             }
+            Self::ResultCountExpected {
+                expected,
+                count,
+                operation,
+            } => {
+                write!(
+                    formatter,
+                    "{expected} results expected but found {count} results: {operation}"
+                )
+            }
             Self::ResultNotFound(name) => {
                 write!(formatter, "result {name} not found")
             }
+            Self::ResultTypeInference(name) => {
+                write!(
+                    formatter,
+                    "failed to infer result types of operation: {name}"
+                )
+            }
             Self::RunPass => write!(formatter, "failed to run pass"),
+            Self::SingleResultExpected { count, operation } => {
+                write!(
+                    formatter,
+                    "single result expected but found {count} results: {operation}"
+                )
+            }
             Self::TypeExpected(r#type, actual) => {
                 write!(formatter, "{type} type expected: {actual}")
             }
@@ -1,8 +1,16 @@
+mod invoke;
+mod mem_ref_descriptor;
+
+pub use self::{
+    invoke::{InvokeArguments, InvokeResult, InvokeValue},
+    mem_ref_descriptor::RankedMemRefDescriptor,
+};
 use crate::{ir::Module, logical_result::LogicalResult, string_ref::StringRef, Error};
 use mlir_sys::{
     mlirExecutionEngineCreate, mlirExecutionEngineDestroy, mlirExecutionEngineDumpToObjectFile,
     mlirExecutionEngineInvokePacked, mlirExecutionEngineRegisterSymbol, MlirExecutionEngine,
 };
+use std::mem::MaybeUninit;
 
 /// An execution engine.
 pub struct ExecutionEngine {
@@ -56,6 +64,33 @@ impl ExecutionEngine {
         }
     }
 
+    /// Invokes a function in a module with typed arguments and a result.
+    ///
+    /// Arguments and a result are packed for
+    /// [`invoke_packed`](Self::invoke_packed), which expects an array of
+    /// pointers to arguments followed by a pointer to a result. Each pointer
+    /// points to a value in the C ABI layout of its LLVM type.
+    ///
+    /// # Safety
+    ///
+    /// Types of the arguments and result must match a signature of the
+    /// function. Otherwise, calling this function might result in undefined
+    /// behavior.
+    pub unsafe fn invoke<R: InvokeResult>(
+        &self,
+        name: &str,
+        mut arguments: impl InvokeArguments,
+    ) -> Result<R, Error> {
+        let mut result = MaybeUninit::uninit();
+        let mut pointers = arguments.pointers();
+
+        pointers.extend(R::pointer(&mut result));
+
+        self.invoke_packed(name, &mut pointers)?;
+
+        Ok(result.assume_init())
+    }
+
     /// Register a symbol. This symbol will be accessible to the JIT'd codes.
     ///
     /// # Safety
@@ -132,6 +167,88 @@ mod tests {
         assert_eq!(result, 84);
     }
 
+    #[test]
+    fn invoke() {
+        let context = create_test_context();
+
+        let mut module = Module::parse(
+            &context,
+            r#"
+            module {
+                func.func @add(%arg0 : i32, %arg1 : i32) -> i32 attributes { llvm.emit_c_interface } {
+                    %res = arith.addi %arg0, %arg1 : i32
+                    return %res : i32
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let pass_manager = pass::PassManager::new(&context);
+        pass_manager.add_pass(pass::conversion::create_func_to_llvm());
+
+        pass_manager
+            .nested_under("func.func")
+            .add_pass(pass::conversion::create_arith_to_llvm());
+
+        assert_eq!(pass_manager.run(&mut module), Ok(()));
+
+        let engine = ExecutionEngine::new(&module, 2, &[], false);
+
+        assert_eq!(
+            unsafe { engine.invoke::<i32>("add", (40i32, 2i32)) },
+            Ok(42)
+        );
+    }
+
+    #[test]
+    fn invoke_with_mem_ref() {
+        let context = create_test_context();
+
+        let mut module = Module::parse(
+            &context,
+            r#"
+            module {
+                func.func @fill(%arg0 : memref<2xi32>) attributes { llvm.emit_c_interface } {
+                    %c0 = arith.constant 0 : index
+                    %c1 = arith.constant 1 : index
+                    %a = arith.constant 42 : i32
+                    %b = arith.constant 13 : i32
+                    memref.store %a, %arg0[%c0] : memref<2xi32>
+                    memref.store %b, %arg0[%c1] : memref<2xi32>
+                    return
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let pass_manager = pass::PassManager::new(&context);
+        pass_manager.add_pass(pass::conversion::create_mem_ref_to_llvm());
+        pass_manager
+            .nested_under("func.func")
+            .add_pass(pass::conversion::create_arith_to_llvm());
+        pass_manager.add_pass(pass::conversion::create_func_to_llvm());
+        pass_manager.add_pass(pass::conversion::create_reconcile_unrealized_casts());
+
+        assert_eq!(pass_manager.run(&mut module), Ok(()));
+
+        let engine = ExecutionEngine::new(&module, 2, &[], false);
+        let mut data = vec![0i32; 2];
+        let mut descriptor = RankedMemRefDescriptor::new(&mut data, [2]);
+
+        assert_eq!(
+            unsafe {
+                engine.invoke::<()>(
+                    "_mlir_ciface_fill",
+                    (&mut descriptor as *mut RankedMemRefDescriptor<i32, 1>,),
+                )
+            },
+            Ok(())
+        );
+        assert_eq!(data, [42, 13]);
+    }
+
     #[test]
     fn dump_to_object_file() {
         let context = create_test_context();
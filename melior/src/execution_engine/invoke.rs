@@ -0,0 +1,72 @@
+use std::mem::MaybeUninit;
+
+/// A value passed to or returned from a function invoked by an execution
+/// engine.
+///
+/// # Safety
+///
+/// A type must have the same size and alignment as its corresponding LLVM
+/// type.
+pub unsafe trait InvokeValue: Copy {}
+
+macro_rules! impl_invoke_values {
+    ($($type:ty),* $(,)?) => {
+        $(unsafe impl InvokeValue for $type {})*
+    };
+}
+
+impl_invoke_values!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+unsafe impl<T> InvokeValue for *const T {}
+
+unsafe impl<T> InvokeValue for *mut T {}
+
+/// Arguments of a function invoked by an execution engine.
+///
+/// It is implemented for tuples of up to 8 [`InvokeValue`]s.
+pub trait InvokeArguments {
+    /// Gets pointers to arguments.
+    fn pointers(&mut self) -> Vec<*mut ()>;
+}
+
+macro_rules! impl_invoke_arguments {
+    ($($type:ident $name:ident),*) => {
+        impl<$($type: InvokeValue),*> InvokeArguments for ($($type,)*) {
+            fn pointers(&mut self) -> Vec<*mut ()> {
+                let ($($name,)*) = self;
+
+                vec![$($name as *mut $type as *mut ()),*]
+            }
+        }
+    };
+}
+
+impl_invoke_arguments!();
+impl_invoke_arguments!(A a);
+impl_invoke_arguments!(A a, B b);
+impl_invoke_arguments!(A a, B b, C c);
+impl_invoke_arguments!(A a, B b, C c, D d);
+impl_invoke_arguments!(A a, B b, C c, D d, E e);
+impl_invoke_arguments!(A a, B b, C c, D d, E e, F f);
+impl_invoke_arguments!(A a, B b, C c, D d, E e, F f, G g);
+impl_invoke_arguments!(A a, B b, C c, D d, E e, F f, G g, H h);
+
+/// A result of a function invoked by an execution engine.
+///
+/// It is implemented for `()` and [`InvokeValue`]s.
+pub trait InvokeResult: Sized {
+    /// Gets a pointer to a result if a function returns any.
+    fn pointer(result: &mut MaybeUninit<Self>) -> Option<*mut ()>;
+}
+
+impl InvokeResult for () {
+    fn pointer(_: &mut MaybeUninit<Self>) -> Option<*mut ()> {
+        None
+    }
+}
+
+impl<T: InvokeValue> InvokeResult for T {
+    fn pointer(result: &mut MaybeUninit<Self>) -> Option<*mut ()> {
+        Some(result.as_mut_ptr() as *mut ())
+    }
+}
@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+
+/// A descriptor of a ranked memref.
+///
+/// It has the same layout as a descriptor that a function with the
+/// `llvm.emit_c_interface` attribute takes by a pointer for each ranked memref
+/// argument: allocated and aligned pointers, an offset, sizes, and strides.
+/// Pass a pointer to the descriptor to the `_mlir_ciface_` prefixed function
+/// to let JIT-compiled code access Rust memory.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RankedMemRefDescriptor<'a, T, const N: usize> {
+    allocated: *mut T,
+    aligned: *mut T,
+    offset: i64,
+    sizes: [i64; N],
+    strides: [i64; N],
+    _data: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T, const N: usize> RankedMemRefDescriptor<'a, T, N> {
+    /// Creates a descriptor of a slice in the row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a product of sizes does not match a length of the slice.
+    pub fn new(data: &'a mut [T], sizes: [usize; N]) -> Self {
+        assert_eq!(
+            sizes.iter().product::<usize>(),
+            data.len(),
+            "memref sizes match data length"
+        );
+
+        let mut strides = [1; N];
+
+        for index in (0..N.saturating_sub(1)).rev() {
+            strides[index] = strides[index + 1] * sizes[index + 1] as i64;
+        }
+
+        Self {
+            allocated: data.as_mut_ptr(),
+            aligned: data.as_mut_ptr(),
+            offset: 0,
+            sizes: sizes.map(|size| size as i64),
+            strides,
+            _data: Default::default(),
+        }
+    }
+
+    /// Gets an offset.
+    pub fn offset(&self) -> i64 {
+        self.offset
+    }
+
+    /// Gets sizes.
+    pub fn sizes(&self) -> &[i64; N] {
+        &self.sizes
+    }
+
+    /// Gets strides.
+    pub fn strides(&self) -> &[i64; N] {
+        &self.strides
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let mut data = [0; 6];
+        let descriptor = RankedMemRefDescriptor::new(&mut data, [2, 3]);
+
+        assert_eq!(descriptor.offset(), 0);
+        assert_eq!(descriptor.sizes(), &[2, 3]);
+        assert_eq!(descriptor.strides(), &[3, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "memref sizes match data length")]
+    fn new_with_invalid_sizes() {
+        RankedMemRefDescriptor::new(&mut [0; 6], [2, 2]);
+    }
+}
@@ -3,11 +3,15 @@
 mod affine_map;
 pub mod attribute;
 pub mod block;
+mod cse;
+mod dominance;
 mod identifier;
+mod ir_mapping;
 mod location;
 mod module;
 pub mod operation;
 mod region;
+mod sealed;
 pub mod r#type;
 mod value;
 
@@ -15,7 +19,10 @@ pub use self::{
     affine_map::AffineMap,
     attribute::{Attribute, AttributeLike},
     block::{Block, BlockRef},
+    cse::eliminate_common_subexpressions,
+    dominance::Dominance,
     identifier::Identifier,
+    ir_mapping::IrMapping,
     location::Location,
     module::Module,
     operation::{Operation, OperationRef},
@@ -4,9 +4,12 @@
 mod r#macro;
 mod array;
 mod attribute_like;
+mod dense_bool_array;
 mod dense_elements;
 mod dense_i32_array;
 mod dense_i64_array;
+mod dense_resource_elements;
+mod dictionary;
 mod flat_symbol_ref;
 mod float;
 mod integer;
@@ -14,12 +17,20 @@ mod string;
 mod r#type;
 
 pub use self::{
-    array::ArrayAttribute, attribute_like::AttributeLike, dense_elements::DenseElementsAttribute,
+    array::ArrayAttribute, attribute_like::AttributeLike,
+    dense_bool_array::DenseBoolArrayAttribute, dense_elements::DenseElementsAttribute,
     dense_i32_array::DenseI32ArrayAttribute, dense_i64_array::DenseI64ArrayAttribute,
+    dense_resource_elements::DenseResourceElementsAttribute, dictionary::DictionaryAttribute,
     flat_symbol_ref::FlatSymbolRefAttribute, float::FloatAttribute, integer::IntegerAttribute,
     r#type::TypeAttribute, string::StringAttribute,
 };
-use crate::{context::Context, string_ref::StringRef, utility::print_callback};
+use super::{sealed::Sealed, Type};
+use crate::{
+    context::Context,
+    string_ref::StringRef,
+    utility::{join_diagnostics, print_callback},
+    Error,
+};
 use mlir_sys::{
     mlirAttributeEqual, mlirAttributeGetNull, mlirAttributeParseGet, mlirAttributePrint,
     mlirUnitAttrGet, MlirAttribute,
@@ -31,6 +42,19 @@ use std::{
 };
 
 /// An attribute.
+///
+/// Attributes are neither [`Send`] nor [`Sync`]. Multi-threading of
+/// contexts can be disabled from safe code, and the
+/// [`Context`](crate::Context) that attributes borrow is not [`Sync`] either.
+///
+/// ```compile_fail
+/// use melior::{ir::Attribute, Context};
+///
+/// let context = Box::leak(Box::new(Context::new()));
+/// let attribute = Attribute::unit(context);
+///
+/// std::thread::spawn(move || attribute);
+/// ```
 // Attributes are always values but their internal storage is owned by contexts.
 #[derive(Clone, Copy)]
 pub struct Attribute<'c> {
@@ -40,13 +64,18 @@ pub struct Attribute<'c> {
 
 impl<'c> Attribute<'c> {
     /// Parses an attribute.
-    pub fn parse(context: &'c Context, source: &str) -> Option<Self> {
-        unsafe {
+    ///
+    /// Diagnostics emitted on failure are captured into an error instead of
+    /// being propagated to other diagnostic handlers.
+    pub fn parse(context: &'c Context, source: &str) -> Result<Self, Error> {
+        let (attribute, diagnostics) = context.with_captured_diagnostics(|| unsafe {
             Self::from_option_raw(mlirAttributeParseGet(
                 context.to_raw(),
                 StringRef::from(source).to_raw(),
             ))
-        }
+        });
+
+        attribute.ok_or_else(|| Error::ParseAttribute(join_diagnostics(&diagnostics)))
     }
 
     /// Creates a unit attribute.
@@ -54,6 +83,27 @@ impl<'c> Attribute<'c> {
         unsafe { Self::from_raw(mlirUnitAttrGet(context.to_raw())) }
     }
 
+    /// Returns `true` if an attribute is equal to an attribute parsed from
+    /// text.
+    ///
+    /// It returns `false` if the text fails to parse.
+    pub fn equals_text(&self, context: &Context, text: &str) -> bool {
+        Attribute::parse(context, text).map_or(false, |attribute| *self == attribute)
+    }
+
+    /// Walks attributes and types nested in an attribute in pre-order.
+    ///
+    /// The C API does not expose `walkSubElements`, so only elements of array
+    /// attributes, values of dictionary attributes, and types of type
+    /// attributes are visited.
+    pub fn walk_subelements(
+        &self,
+        mut on_attribute: impl FnMut(Attribute<'c>),
+        mut on_type: impl FnMut(Type<'c>),
+    ) {
+        walk_subelements(*self, &mut on_attribute, &mut on_type)
+    }
+
     pub(crate) unsafe fn null() -> Self {
         unsafe { Self::from_raw(mlirAttributeGetNull()) }
     }
@@ -84,6 +134,8 @@ impl<'c> Attribute<'c> {
     }
 }
 
+impl<'c> Sealed for Attribute<'c> {}
+
 impl<'c> AttributeLike<'c> for Attribute<'c> {
     fn to_raw(&self) -> MlirAttribute {
         self.raw
@@ -120,12 +172,43 @@ impl<'c> Debug for Attribute<'c> {
     }
 }
 
+fn walk_subelements<'c>(
+    attribute: Attribute<'c>,
+    on_attribute: &mut dyn FnMut(Attribute<'c>),
+    on_type: &mut dyn FnMut(Type<'c>),
+) {
+    let mut visit = |attribute| {
+        on_attribute(attribute);
+        walk_subelements(attribute, on_attribute, on_type);
+    };
+
+    if let Ok(array) = ArrayAttribute::try_from(attribute) {
+        for index in 0..array.len() {
+            visit(array.element(index).expect("valid array element index"));
+        }
+    } else if let Ok(dictionary) = DictionaryAttribute::try_from(attribute) {
+        for index in 0..dictionary.len() {
+            visit(
+                dictionary
+                    .element(index)
+                    .expect("valid dictionary element index")
+                    .1,
+            );
+        }
+    } else if let Ok(attribute) = TypeAttribute::try_from(attribute) {
+        on_type(attribute.value());
+    }
+}
+
 from_subtypes!(
     Attribute,
     ArrayAttribute,
+    DenseBoolArrayAttribute,
     DenseElementsAttribute,
     DenseI32ArrayAttribute,
     DenseI64ArrayAttribute,
+    DenseResourceElementsAttribute,
+    DictionaryAttribute,
     FlatSymbolRefAttribute,
     FloatAttribute,
     IntegerAttribute,
@@ -141,21 +224,44 @@ mod tests {
         test::create_test_context,
     };
 
+    #[test]
+    fn walk_subelements() {
+        let context = create_test_context();
+        let mut attributes = vec![];
+        let mut types = vec![];
+
+        Attribute::parse(&context, "[i32, [index], {foo = f32}, 42]")
+            .unwrap()
+            .walk_subelements(
+                |attribute| attributes.push(attribute),
+                |r#type| types.push(r#type),
+            );
+
+        assert_eq!(attributes.len(), 6);
+        assert_eq!(
+            types,
+            vec![
+                Type::parse(&context, "i32").unwrap(),
+                Type::parse(&context, "index").unwrap(),
+                Type::parse(&context, "f32").unwrap(),
+            ]
+        );
+    }
+
     #[test]
     fn parse() {
         let context = create_test_context();
         for attribute in ["unit", "i32", r#""foo""#] {
-            assert!(Attribute::parse(&context, attribute).is_some());
+            assert!(Attribute::parse(&context, attribute).is_ok());
         }
     }
 
     #[test]
-    fn parse_none() {
-        // Note: this test will print a warning if LLVM was compiled with asserts.
-        // `<mlir_parser_buffer>:1:1: error: expected attribute value
-        // z
-        // ^`
-        assert!(Attribute::parse(&Context::new(), "z").is_none());
+    fn parse_error() {
+        assert_eq!(
+            Attribute::parse(&Context::new(), "z"),
+            Err(Error::ParseAttribute("expected attribute value".into()))
+        );
     }
 
     #[test]
@@ -282,6 +388,18 @@ mod tests {
         assert!(Attribute::parse(&context, "index").unwrap().is_type());
     }
 
+    #[test]
+    fn to_bytes() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::parse(&context, r#""foo\FFbar""#)
+                .unwrap()
+                .to_bytes(),
+            br#""foo\FFbar""#
+        );
+    }
+
     #[test]
     fn is_unit() {
         let context = create_test_context();
@@ -312,6 +430,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn equals_text() {
+        let context = create_test_context();
+        let attribute = Attribute::from(IntegerAttribute::new(42, Type::index(&context)).unwrap());
+
+        assert!(attribute.equals_text(&context, "42 : index"));
+        assert!(!attribute.equals_text(&context, "42 : i64"));
+        assert!(!attribute.equals_text(&context, "z"));
+    }
+
     #[test]
     fn display() {
         let context = create_test_context();
@@ -320,4 +448,20 @@ mod tests {
             "unit"
         );
     }
+
+    #[test]
+    fn display_attribute_like() {
+        fn print<'c>(attribute: impl AttributeLike<'c>) -> String {
+            attribute.to_string()
+        }
+
+        let context = create_test_context();
+
+        assert_eq!(print(Attribute::unit(&context)), "unit");
+        assert_eq!(print(StringAttribute::new(&context, "foo")), "\"foo\"");
+        assert_eq!(
+            print(IntegerAttribute::new(42, Type::index(&context)).unwrap()),
+            "42 : index"
+        );
+    }
 }
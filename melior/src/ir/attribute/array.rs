@@ -67,9 +67,9 @@ mod tests {
         let context = create_test_context();
         let r#type = IntegerType::new(&context, 64).into();
         let attributes = [
-            IntegerAttribute::new(1, r#type).into(),
-            IntegerAttribute::new(2, r#type).into(),
-            IntegerAttribute::new(3, r#type).into(),
+            IntegerAttribute::new(1, r#type).unwrap().into(),
+            IntegerAttribute::new(2, r#type).unwrap().into(),
+            IntegerAttribute::new(3, r#type).unwrap().into(),
         ];
 
         let attribute = ArrayAttribute::new(&context, &attributes);
@@ -88,7 +88,9 @@ mod tests {
         let context = create_test_context();
         let attribute = ArrayAttribute::new(
             &context,
-            &[IntegerAttribute::new(1, Type::index(&context)).into()],
+            &[IntegerAttribute::new(1, Type::index(&context))
+                .unwrap()
+                .into()],
         );
 
         assert_eq!(attribute.len(), 1);
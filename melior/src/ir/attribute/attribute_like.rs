@@ -1,15 +1,17 @@
 use crate::{
-    ir::{r#type::TypeId, Type},
+    ir::{r#type::TypeId, sealed::Sealed, Type},
+    utility::print_bytes_callback,
     ContextRef,
 };
 use melior_macro::attribute_check_functions;
 use mlir_sys::{
     mlirAttributeDump, mlirAttributeGetContext, mlirAttributeGetType, mlirAttributeGetTypeID,
-    MlirAttribute,
+    mlirAttributePrint, MlirAttribute,
 };
+use std::{ffi::c_void, fmt::Display};
 
 /// Trait for attribute-like types.
-pub trait AttributeLike<'c> {
+pub trait AttributeLike<'c>: Display + Sealed {
     /// Converts a attribute into a raw object.
     fn to_raw(&self) -> MlirAttribute;
 
@@ -33,6 +35,21 @@ pub trait AttributeLike<'c> {
         unsafe { mlirAttributeDump(self.to_raw()) }
     }
 
+    /// Converts an attribute into bytes without UTF-8 validation.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+
+        unsafe {
+            mlirAttributePrint(
+                self.to_raw(),
+                Some(print_bytes_callback),
+                &mut bytes as *mut _ as *mut c_void,
+            )
+        }
+
+        bytes
+    }
+
     attribute_check_functions!(
         mlirAttributeIsAAffineMap,
         mlirAttributeIsAArray,
@@ -62,4 +79,12 @@ pub trait AttributeLike<'c> {
         mlirAttributeIsAType,
         mlirAttributeIsAUnit,
     );
+
+    /// Returns `true` if an attribute is dense resource elements.
+    fn is_dense_resource(&self) -> bool {
+        // The C API has no predicate for dense resource elements, but they are
+        // the only builtin elements attributes that are neither dense nor
+        // sparse.
+        self.is_elements() && !self.is_dense_elements() && !self.is_sparse_elements()
+    }
 }
@@ -79,4 +79,14 @@ mod tests {
 
         assert_eq!(attribute.len(), 3);
     }
+
+    #[test]
+    fn to_string() {
+        let context = create_test_context();
+
+        assert_eq!(
+            DenseI64ArrayAttribute::new(&context, &[1, 2, 3]).to_string(),
+            "array<i64: 1, 2, 3>"
+        );
+    }
 }
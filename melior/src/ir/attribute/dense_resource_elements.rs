@@ -0,0 +1,251 @@
+use super::{Attribute, AttributeLike};
+use crate::{
+    ir::{r#type::IntegerType, Type, TypeLike},
+    string_ref::StringRef,
+    Error,
+};
+use mlir_sys::{
+    mlirDenseDoubleResourceElementsAttrGetValue, mlirDenseFloatResourceElementsAttrGetValue,
+    mlirDenseInt16ResourceElementsAttrGetValue, mlirDenseInt32ResourceElementsAttrGetValue,
+    mlirDenseInt64ResourceElementsAttrGetValue, mlirDenseInt8ResourceElementsAttrGetValue,
+    mlirDenseUInt16ResourceElementsAttrGetValue, mlirDenseUInt32ResourceElementsAttrGetValue,
+    mlirDenseUInt64ResourceElementsAttrGetValue, mlirDenseUInt8ResourceElementsAttrGetValue,
+    mlirElementsAttrGetNumElements, mlirShapedTypeGetElementType,
+    mlirUnmanagedDenseUInt8ResourceElementsAttrGet, MlirAttribute,
+};
+
+/// A dense resource elements attribute.
+#[derive(Clone, Copy)]
+pub struct DenseResourceElementsAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DenseResourceElementsAttribute<'c> {
+    /// Creates a dense resource elements attribute from bytes.
+    ///
+    /// The bytes are not copied, so they must outlive the attribute. The
+    /// element type of the shaped type must be an 8-bit integer type.
+    pub fn new(r#type: Type<'c>, name: &str, bytes: &'c [u8]) -> Result<Self, Error> {
+        if !r#type.is_shaped() {
+            return Err(Error::TypeExpected("shaped", r#type.to_string()));
+        }
+
+        let element_type = unsafe { Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw())) };
+
+        if !is_integer(element_type, 8, false) {
+            return Err(Error::ElementExpected {
+                r#type: "8-bit integer",
+                value: r#type.to_string(),
+            });
+        }
+
+        Ok(unsafe {
+            Self::from_raw(mlirUnmanagedDenseUInt8ResourceElementsAttrGet(
+                r#type.to_raw(),
+                StringRef::from(name).to_raw(),
+                bytes.len() as isize,
+                bytes.as_ptr(),
+            ))
+        })
+    }
+
+    /// Gets a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if an attribute is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets an i8 element.
+    pub fn i8_element(&self, index: usize) -> Result<i8, Error> {
+        self.integer_element(index, 8, true, mlirDenseInt8ResourceElementsAttrGetValue)
+    }
+
+    /// Gets a u8 element.
+    pub fn u8_element(&self, index: usize) -> Result<u8, Error> {
+        self.integer_element(index, 8, false, mlirDenseUInt8ResourceElementsAttrGetValue)
+    }
+
+    /// Gets an i16 element.
+    pub fn i16_element(&self, index: usize) -> Result<i16, Error> {
+        self.integer_element(index, 16, true, mlirDenseInt16ResourceElementsAttrGetValue)
+    }
+
+    /// Gets a u16 element.
+    pub fn u16_element(&self, index: usize) -> Result<u16, Error> {
+        self.integer_element(
+            index,
+            16,
+            false,
+            mlirDenseUInt16ResourceElementsAttrGetValue,
+        )
+    }
+
+    /// Gets an i32 element.
+    pub fn i32_element(&self, index: usize) -> Result<i32, Error> {
+        self.integer_element(index, 32, true, mlirDenseInt32ResourceElementsAttrGetValue)
+    }
+
+    /// Gets a u32 element.
+    pub fn u32_element(&self, index: usize) -> Result<u32, Error> {
+        self.integer_element(
+            index,
+            32,
+            false,
+            mlirDenseUInt32ResourceElementsAttrGetValue,
+        )
+    }
+
+    /// Gets an i64 element.
+    pub fn i64_element(&self, index: usize) -> Result<i64, Error> {
+        self.integer_element(index, 64, true, mlirDenseInt64ResourceElementsAttrGetValue)
+    }
+
+    /// Gets a u64 element.
+    pub fn u64_element(&self, index: usize) -> Result<u64, Error> {
+        self.integer_element(
+            index,
+            64,
+            false,
+            mlirDenseUInt64ResourceElementsAttrGetValue,
+        )
+    }
+
+    /// Gets an f32 element.
+    pub fn f32_element(&self, index: usize) -> Result<f32, Error> {
+        self.element(
+            index,
+            self.element_type().is_f32(),
+            "f32",
+            mlirDenseFloatResourceElementsAttrGetValue,
+        )
+    }
+
+    /// Gets an f64 element.
+    pub fn f64_element(&self, index: usize) -> Result<f64, Error> {
+        self.element(
+            index,
+            self.element_type().is_f64(),
+            "f64",
+            mlirDenseDoubleResourceElementsAttrGetValue,
+        )
+    }
+
+    fn element_type(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirShapedTypeGetElementType(self.r#type().to_raw())) }
+    }
+
+    fn integer_element<T>(
+        &self,
+        index: usize,
+        width: u32,
+        signed: bool,
+        get: unsafe extern "C" fn(MlirAttribute, isize) -> T,
+    ) -> Result<T, Error> {
+        self.element(
+            index,
+            is_integer(self.element_type(), width, signed),
+            "integer",
+            get,
+        )
+    }
+
+    fn element<T>(
+        &self,
+        index: usize,
+        valid: bool,
+        r#type: &'static str,
+        get: unsafe extern "C" fn(MlirAttribute, isize) -> T,
+    ) -> Result<T, Error> {
+        if !valid {
+            Err(Error::ElementExpected {
+                r#type,
+                value: self.to_string(),
+            })
+        } else if index < self.len() {
+            Ok(unsafe { get(self.attribute.to_raw(), index as isize) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dense resource element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+}
+
+attribute_traits!(
+    DenseResourceElementsAttribute,
+    is_dense_resource,
+    "dense resource elements"
+);
+
+// Resource element getters accept signless integers or integers of the same
+// signedness only.
+fn is_integer(r#type: Type, width: u32, signed: bool) -> bool {
+    IntegerType::try_from(r#type).map_or(false, |r#type| {
+        r#type.width() == width
+            && (r#type.is_signless()
+                || if signed {
+                    r#type.is_signed()
+                } else {
+                    r#type.is_unsigned()
+                })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::r#type::RankedTensorType, test::create_test_context};
+
+    #[test]
+    fn u8_element() {
+        let context = create_test_context();
+        let bytes = [1, 2, 3, 4];
+        let attribute = DenseResourceElementsAttribute::new(
+            RankedTensorType::new(&[4], IntegerType::new(&context, 8).into(), None).into(),
+            "foo",
+            &bytes,
+        )
+        .unwrap();
+
+        assert_eq!(attribute.len(), 4);
+        assert_eq!(attribute.u8_element(2), Ok(3));
+        assert_eq!(
+            attribute.u8_element(4),
+            Err(Error::PositionOutOfBounds {
+                name: "dense resource element",
+                value: attribute.to_string(),
+                index: 4,
+            })
+        );
+        assert_eq!(
+            attribute.i32_element(0),
+            Err(Error::ElementExpected {
+                r#type: "integer",
+                value: attribute.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn is_dense_resource() {
+        let context = create_test_context();
+        let bytes = [0];
+        let attribute = DenseResourceElementsAttribute::new(
+            RankedTensorType::new(&[1], IntegerType::new(&context, 8).into(), None).into(),
+            "foo",
+            &bytes,
+        )
+        .unwrap();
+
+        assert!(Attribute::from(attribute).is_dense_resource());
+        assert!(!Attribute::parse(&context, "dense<0> : tensor<1xi8>")
+            .unwrap()
+            .is_dense_resource());
+    }
+}
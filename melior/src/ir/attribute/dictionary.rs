@@ -0,0 +1,138 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::Identifier, Context, Error, StringRef};
+use mlir_sys::{
+    mlirDictionaryAttrGet, mlirDictionaryAttrGetElement, mlirDictionaryAttrGetElementByName,
+    mlirDictionaryAttrGetNumElements, mlirNamedAttributeGet, MlirAttribute,
+};
+
+/// A dictionary attribute.
+#[derive(Clone, Copy)]
+pub struct DictionaryAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DictionaryAttribute<'c> {
+    /// Creates a dictionary attribute.
+    pub fn new(context: &'c Context, values: &[(Identifier<'c>, Attribute<'c>)]) -> Self {
+        let values = values
+            .iter()
+            .map(|(name, attribute)| unsafe {
+                mlirNamedAttributeGet(name.to_raw(), attribute.to_raw())
+            })
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirDictionaryAttrGet(
+                context.to_raw(),
+                values.len() as isize,
+                values.as_ptr(),
+            ))
+        }
+    }
+
+    /// Gets a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirDictionaryAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if a dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets an element at a position.
+    pub fn element(&self, index: usize) -> Result<(Identifier<'c>, Attribute<'c>), Error> {
+        if index < self.len() {
+            unsafe {
+                let named_attribute =
+                    mlirDictionaryAttrGetElement(self.attribute.to_raw(), index as isize);
+
+                Ok((
+                    Identifier::from_raw(named_attribute.name),
+                    Attribute::from_raw(named_attribute.attribute),
+                ))
+            }
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dictionary element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Gets an element by name.
+    pub fn get(&self, name: &str) -> Option<Attribute<'c>> {
+        unsafe {
+            Attribute::from_option_raw(mlirDictionaryAttrGetElementByName(
+                self.attribute.to_raw(),
+                StringRef::from(name).to_raw(),
+            ))
+        }
+    }
+}
+
+attribute_traits!(DictionaryAttribute, is_dictionary, "dictionary");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::attribute::StringAttribute, test::create_test_context};
+
+    fn create_dictionary(context: &Context) -> DictionaryAttribute {
+        DictionaryAttribute::new(
+            context,
+            &[
+                (
+                    Identifier::new(context, "foo"),
+                    StringAttribute::new(context, "bar").into(),
+                ),
+                (Identifier::new(context, "baz"), Attribute::unit(context)),
+            ],
+        )
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            create_dictionary(&context).to_string(),
+            "{baz, foo = \"bar\"}"
+        );
+    }
+
+    #[test]
+    fn len() {
+        let context = create_test_context();
+
+        assert_eq!(create_dictionary(&context).len(), 2);
+    }
+
+    #[test]
+    fn element() {
+        let context = create_test_context();
+        let dictionary = create_dictionary(&context);
+
+        assert_eq!(
+            dictionary.element(0).unwrap(),
+            (Identifier::new(&context, "baz"), Attribute::unit(&context))
+        );
+        assert!(matches!(
+            dictionary.element(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn get() {
+        let context = create_test_context();
+        let dictionary = create_dictionary(&context);
+
+        assert_eq!(
+            dictionary.get("foo"),
+            Some(StringAttribute::new(&context, "bar").into())
+        );
+        assert_eq!(dictionary.get("qux"), None);
+    }
+}
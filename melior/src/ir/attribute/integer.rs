@@ -1,9 +1,9 @@
 use super::{Attribute, AttributeLike};
 use crate::{
-    ir::{Type, TypeLike},
+    ir::{r#type::IntegerType, Type, TypeLike},
     Error,
 };
-use mlir_sys::{mlirIntegerAttrGet, MlirAttribute};
+use mlir_sys::{mlirIntegerAttrGet, mlirIntegerAttrGetValueInt, MlirAttribute};
 
 /// An integer attribute.
 #[derive(Clone, Copy)]
@@ -13,9 +13,223 @@ pub struct IntegerAttribute<'c> {
 
 impl<'c> IntegerAttribute<'c> {
     /// Creates an integer attribute.
-    pub fn new(integer: i64, r#type: Type<'c>) -> Self {
+    ///
+    /// It returns an error if the integer does not fit in the bit width and
+    /// signedness of the given integer type.
+    pub fn new(integer: i64, r#type: Type<'c>) -> Result<Self, Error> {
+        if !r#type.is_index() {
+            let integer_type = IntegerType::try_from(r#type)?;
+            let (minimum, maximum) = integer_range(&integer_type);
+
+            if !(minimum..=maximum).contains(&(integer as i128)) {
+                return Err(Error::IntegerOutOfRange {
+                    integer,
+                    r#type: r#type.to_string(),
+                });
+            }
+        }
+
+        Ok(Self::new_wrapping(integer, r#type))
+    }
+
+    /// Creates an integer attribute without checking its range.
+    ///
+    /// An integer which does not fit in a given type is truncated to its bit
+    /// width.
+    pub fn new_wrapping(integer: i64, r#type: Type<'c>) -> Self {
         unsafe { Self::from_raw(mlirIntegerAttrGet(r#type.to_raw(), integer)) }
     }
+
+    /// Gets a value.
+    ///
+    /// Values wider than 64 bits are truncated to 64 bits. Use
+    /// [`value_bytes`](Self::value_bytes) to get them exactly.
+    pub fn value(&self) -> i64 {
+        unsafe { mlirIntegerAttrGetValueInt(self.to_raw()) }
+    }
+
+    /// Gets a value as little-endian bytes in two's complement.
+    ///
+    /// The number of bytes is a bit width of an integer type rounded up to
+    /// whole bytes, so integers wider than 64 bits are represented exactly.
+    /// Values of the `index` type are 64 bits wide.
+    ///
+    /// This is the only exact accessor of values. The C API only exposes
+    /// values truncated to 64 bits, so a printed value in decimal is converted
+    /// instead.
+    pub fn value_bytes(&self) -> Vec<u8> {
+        let width = IntegerType::try_from(self.r#type()).map_or(64, |r#type| r#type.width());
+        let mut bytes = vec![0u8; (width as usize + 7) / 8];
+        let string = self.to_string();
+        let value = string.split(' ').next().unwrap_or_default();
+        let (negative, digits) = match value {
+            "true" => (false, "1"),
+            "false" => (false, "0"),
+            value => value
+                .strip_prefix('-')
+                .map_or((false, value), |digits| (true, digits)),
+        };
+
+        assert!(
+            !digits.is_empty() && digits.bytes().all(|digit| digit.is_ascii_digit()),
+            "integer attribute value printed in decimal"
+        );
+
+        for digit in digits.bytes() {
+            let mut carry = (digit - b'0') as u16;
+
+            for byte in &mut bytes {
+                let value = *byte as u16 * 10 + carry;
+
+                *byte = value as u8;
+                carry = value >> 8;
+            }
+        }
+
+        if negative {
+            let mut carry = 1;
+
+            for byte in &mut bytes {
+                let value = !*byte as u16 + carry;
+
+                *byte = value as u8;
+                carry = value >> 8;
+            }
+        }
+
+        bytes
+    }
+}
+
+fn integer_range(r#type: &IntegerType) -> (i128, i128) {
+    // Any integer type wider than 64 bits can hold all `i64` values.
+    let width = r#type.width().min(65);
+
+    if width == 0 {
+        (0, 0)
+    } else if r#type.is_unsigned() {
+        (0, (1 << width) - 1)
+    } else if r#type.is_signed() {
+        (-(1 << (width - 1)), (1 << (width - 1)) - 1)
+    } else {
+        // Signless integers can be interpreted as either signed or unsigned.
+        (-(1 << (width - 1)), (1 << width) - 1)
+    }
 }
 
 attribute_traits!(IntegerAttribute, is_integer, "integer");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerAttribute::new(100, IntegerType::new(&context, 8).into())
+                .unwrap()
+                .to_string(),
+            "100 : i8"
+        );
+    }
+
+    #[test]
+    fn context() {
+        let context = Context::new();
+        let attribute = IntegerAttribute::new(42, IntegerType::new(&context, 32).into()).unwrap();
+
+        assert!(*attribute.context() == context);
+    }
+
+    #[test]
+    fn new_out_of_range() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerAttribute::new(300, IntegerType::new(&context, 8).into()).unwrap_err(),
+            Error::IntegerOutOfRange {
+                integer: 300,
+                r#type: "i8".into()
+            }
+        );
+    }
+
+    #[test]
+    fn new_unsigned_negative() {
+        let context = Context::new();
+
+        assert!(IntegerAttribute::new(-1, IntegerType::unsigned(&context, 8).into()).is_err());
+    }
+
+    #[test]
+    fn new_index() {
+        let context = Context::new();
+
+        assert!(IntegerAttribute::new(i64::MAX, Type::index(&context)).is_ok());
+    }
+
+    #[test]
+    fn value() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerAttribute::new(-42, IntegerType::new(&context, 64).into())
+                .unwrap()
+                .value(),
+            -42
+        );
+    }
+
+    #[test]
+    fn value_bytes() {
+        let context = Context::new();
+
+        for value in [0, 1, -1, 42, i64::MIN as i128, i128::MAX, i128::MIN] {
+            let attribute = IntegerAttribute::try_from(
+                Attribute::parse(&context, &format!("{value} : i128")).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                i128::from_le_bytes(attribute.value_bytes().try_into().unwrap()),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn value_bytes_of_narrow_integer() {
+        let context = Context::new();
+
+        assert_eq!(
+            IntegerAttribute::new(-2, IntegerType::new(&context, 8).into())
+                .unwrap()
+                .value_bytes(),
+            vec![0xfe]
+        );
+        assert_eq!(
+            IntegerAttribute::new(1, IntegerType::new(&context, 1).into())
+                .unwrap()
+                .value_bytes(),
+            vec![1]
+        );
+        assert_eq!(
+            IntegerAttribute::new(300, Type::index(&context))
+                .unwrap()
+                .value_bytes(),
+            Vec::from(300i64.to_le_bytes())
+        );
+    }
+
+    #[test]
+    fn new_wrapping() {
+        let context = Context::new();
+
+        assert!(
+            IntegerAttribute::new_wrapping(300, IntegerType::new(&context, 8).into()).is_integer()
+        );
+    }
+}
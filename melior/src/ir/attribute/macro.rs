@@ -22,6 +22,8 @@ macro_rules! attribute_traits {
             }
         }
 
+        impl<'c> crate::ir::sealed::Sealed for $name<'c> {}
+
         impl<'c> crate::ir::attribute::AttributeLike<'c> for $name<'c> {
             fn to_raw(&self) -> mlir_sys::MlirAttribute {
                 self.attribute.to_raw()
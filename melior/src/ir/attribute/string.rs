@@ -1,6 +1,6 @@
 use super::{Attribute, AttributeLike};
 use crate::{Context, Error, StringRef};
-use mlir_sys::{mlirStringAttrGet, MlirAttribute};
+use mlir_sys::{mlirStringAttrGet, mlirStringAttrGetValue, MlirAttribute};
 
 /// A string attribute.
 #[derive(Clone, Copy)]
@@ -18,6 +18,27 @@ impl<'c> StringAttribute<'c> {
             ))
         }
     }
+
+    /// Gets a value.
+    pub fn value(&self) -> StringRef<'c> {
+        unsafe { StringRef::from_raw(mlirStringAttrGetValue(self.to_raw())) }
+    }
 }
 
 attribute_traits!(StringAttribute, is_string, "string");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+
+        assert_eq!(
+            StringAttribute::new(&context, "foo").value().as_str(),
+            Ok("foo")
+        );
+    }
+}
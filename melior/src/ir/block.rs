@@ -3,22 +3,37 @@
 mod argument;
 
 pub use self::argument::BlockArgument;
-use super::{Location, Operation, OperationRef, RegionRef, Type, TypeLike, Value};
-use crate::{context::Context, utility::print_callback, Error};
+use super::{
+    operation::WalkResult, Location, Operation, OperationRef, RegionRef, Type, TypeLike, Value,
+    ValueLike,
+};
+use crate::{
+    context::Context,
+    utility::{block_operations, print_callback, region_blocks},
+    Error,
+};
 use mlir_sys::{
     mlirBlockAddArgument, mlirBlockAppendOwnedOperation, mlirBlockCreate, mlirBlockDestroy,
     mlirBlockDetach, mlirBlockEqual, mlirBlockGetArgument, mlirBlockGetFirstOperation,
     mlirBlockGetNextInRegion, mlirBlockGetNumArguments, mlirBlockGetParentOperation,
     mlirBlockGetParentRegion, mlirBlockGetTerminator, mlirBlockInsertOwnedOperation,
     mlirBlockInsertOwnedOperationAfter, mlirBlockInsertOwnedOperationBefore, mlirBlockPrint,
-    MlirBlock,
+    mlirOpResultGetOwner, mlirOperationGetBlock, mlirOperationGetNextInBlock,
+    mlirOperationGetNumOperands, mlirOperationGetNumRegions, mlirOperationGetNumSuccessors,
+    mlirOperationGetOperand, mlirOperationGetParentOperation, mlirOperationGetRegion,
+    mlirOperationGetSuccessor, mlirOperationRemoveFromParent, mlirRegionGetFirstBlock,
+    mlirRegionInsertOwnedBlockAfter, mlirValueIsABlockArgument, MlirBlock, MlirOperation,
 };
 use std::{
+    array,
+    collections::{HashMap, HashSet},
     ffi::c_void,
     fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
     marker::PhantomData,
     mem::{forget, transmute},
     ops::Deref,
+    ptr::null,
 };
 
 /// A block.
@@ -66,6 +81,16 @@ impl<'c> Block<'c> {
         }
     }
 
+    /// Gets all arguments.
+    pub fn arguments(&self) -> impl Iterator<Item = BlockArgument<'c, '_>> {
+        (0..self.argument_count()).map(|index| self.argument(index).expect("valid argument index"))
+    }
+
+    /// Gets types of all arguments.
+    pub fn argument_types(&self) -> Vec<Type<'c>> {
+        self.arguments().map(|argument| argument.r#type()).collect()
+    }
+
     /// Gets a number of arguments.
     pub fn argument_count(&self) -> usize {
         unsafe { mlirBlockGetNumArguments(self.raw) as usize }
@@ -89,6 +114,23 @@ impl<'c> Block<'c> {
         unsafe { OperationRef::from_option_raw(mlirBlockGetTerminator(self.raw)) }
     }
 
+    /// Gets successors of a block.
+    ///
+    /// Successors are taken from a terminator operation. A block without a
+    /// terminator has no successors.
+    pub fn successors(&self) -> impl Iterator<Item = BlockRef<'c, '_>> {
+        let terminator = unsafe { mlirBlockGetTerminator(self.raw) };
+        let count = if terminator.ptr.is_null() {
+            0
+        } else {
+            unsafe { mlirOperationGetNumSuccessors(terminator) as usize }
+        };
+
+        (0..count).map(move |index| unsafe {
+            BlockRef::from_raw(mlirOperationGetSuccessor(terminator, index as isize))
+        })
+    }
+
     /// Gets a parent region.
     // TODO Store lifetime of regions in blocks, or create another type like
     // `InsertedBlockRef`?
@@ -101,6 +143,18 @@ impl<'c> Block<'c> {
         unsafe { OperationRef::from_option_raw(mlirBlockGetParentOperation(self.raw)) }
     }
 
+    /// Walks operations in a block and regions nested in them in pre-order.
+    ///
+    /// A callback can return [`WalkResult::Skip`] to skip regions of a visited
+    /// operation or [`WalkResult::Interrupt`] to stop a walk, in which case
+    /// this function returns [`WalkResult::Interrupt`] as well.
+    pub fn walk<F: FnMut(OperationRef<'c, '_>) -> WalkResult>(
+        &self,
+        mut callback: F,
+    ) -> WalkResult {
+        unsafe { walk_block(self.raw, &mut callback) }
+    }
+
     /// Adds an argument.
     pub fn add_argument(&self, r#type: Type<'c>, location: Location<'c>) -> Value<'c, '_> {
         unsafe {
@@ -123,6 +177,28 @@ impl<'c> Block<'c> {
         }
     }
 
+    /// Appends an operation and gets its results.
+    ///
+    /// An operation is not appended if it does not have exactly `N` results.
+    pub fn append_operation_results<const N: usize>(
+        &self,
+        operation: Operation<'c>,
+    ) -> Result<[Value<'c, '_>; N], Error> {
+        if operation.result_count() != N {
+            return Err(Error::ResultCountExpected {
+                expected: N,
+                count: operation.result_count(),
+                operation: operation.to_string(),
+            });
+        }
+
+        let operation = self.append_operation(operation);
+
+        Ok(array::from_fn(|index| {
+            operation.result(index).expect("valid result index").into()
+        }))
+    }
+
     /// Inserts an operation.
     // TODO How can we make those update functions take `&mut self`?
     // TODO Use cells?
@@ -187,6 +263,118 @@ impl<'c> Block<'c> {
         }
     }
 
+    /// Splits a block before an operation.
+    ///
+    /// A new block without arguments is inserted after the block and the
+    /// operation and all operations following it are moved into it in order.
+    /// No branch is added between the two blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block is not in a region or the operation is not in the
+    /// block.
+    pub fn split(&self, before: &OperationRef) -> BlockRef<'c, '_> {
+        let region = self.parent_region().expect("block in region");
+
+        assert_eq!(
+            before.block().map(|block| block.to_raw().ptr),
+            Some(self.raw.ptr),
+            "operation in block"
+        );
+
+        unsafe {
+            let block = mlirBlockCreate(0, null(), null());
+
+            mlirRegionInsertOwnedBlockAfter(region.to_raw(), self.raw, block);
+
+            let mut operation = before.to_raw();
+
+            while !operation.ptr.is_null() {
+                let next = mlirOperationGetNextInBlock(operation);
+
+                mlirOperationRemoveFromParent(operation);
+                mlirBlockAppendOwnedOperation(block, operation);
+
+                operation = next;
+            }
+
+            BlockRef::from_raw(block)
+        }
+    }
+
+    /// Sorts operations in a block topologically.
+    ///
+    /// Operations are reordered so that operations defining values precede
+    /// operations using them, including uses in their nested regions. The
+    /// relative order of operations is preserved where unconstrained and a
+    /// terminator is kept at the end of the block.
+    ///
+    /// It returns `false` if operations have cyclic dependencies. In that
+    /// case, the cycles are broken in an original order of operations.
+    pub fn topologically_sort(&self) -> bool {
+        unsafe {
+            let mut operations = block_operations(self.raw);
+            let terminator = mlirBlockGetTerminator(self.raw);
+
+            if !terminator.ptr.is_null() {
+                operations.pop();
+            }
+
+            let indices = operations
+                .iter()
+                .enumerate()
+                .map(|(index, operation)| (operation.ptr, index))
+                .collect::<HashMap<_, _>>();
+            let dependencies = operations
+                .iter()
+                .enumerate()
+                .map(|(index, &operation)| {
+                    let mut dependencies = HashSet::new();
+
+                    collect_dependencies(self.raw, operation, &indices, &mut dependencies);
+                    dependencies.remove(&index);
+                    dependencies
+                })
+                .collect::<Vec<_>>();
+
+            let mut sorted = vec![false; operations.len()];
+            let mut acyclic = true;
+
+            for _ in 0..operations.len() {
+                let index = match (0..operations.len()).find(|&index| {
+                    !sorted[index]
+                        && dependencies[index]
+                            .iter()
+                            .all(|&dependency| sorted[dependency])
+                }) {
+                    Some(index) => index,
+                    None => {
+                        acyclic = false;
+
+                        sorted
+                            .iter()
+                            .position(|&sorted| !sorted)
+                            .expect("unsorted operation")
+                    }
+                };
+
+                sorted[index] = true;
+
+                let operation = operations[index];
+
+                mlirOperationRemoveFromParent(operation);
+
+                if terminator.ptr.is_null() {
+                    mlirBlockAppendOwnedOperation(self.raw, operation);
+                } else {
+                    mlirBlockInsertOwnedOperationBefore(self.raw, terminator, operation);
+                }
+            }
+
+            acyclic
+        }
+    }
+
     /// Gets a next block in a region.
     pub fn next_in_region(&self) -> Option<BlockRef<'c, '_>> {
         unsafe { BlockRef::from_option_raw(mlirBlockGetNextInRegion(self.raw)) }
@@ -299,6 +487,7 @@ impl<'c, 'a> Deref for BlockRef<'c, 'a> {
     }
 }
 
+// Block references are equal only if they point to the same block.
 impl<'c, 'a> PartialEq for BlockRef<'c, 'a> {
     fn eq(&self, other: &Self) -> bool {
         unsafe { mlirBlockEqual(self.raw, other.raw) }
@@ -307,6 +496,12 @@ impl<'c, 'a> PartialEq for BlockRef<'c, 'a> {
 
 impl<'c, 'a> Eq for BlockRef<'c, 'a> {}
 
+impl<'c, 'a> Hash for BlockRef<'c, 'a> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.raw.ptr.hash(hasher);
+    }
+}
+
 impl<'c, 'a> Display for BlockRef<'c, 'a> {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         Display::fmt(self.deref(), formatter)
@@ -319,13 +514,91 @@ impl<'c, 'a> Debug for BlockRef<'c, 'a> {
     }
 }
 
+unsafe fn walk_block<'c, F: FnMut(OperationRef<'c, '_>) -> WalkResult>(
+    block: MlirBlock,
+    callback: &mut F,
+) -> WalkResult {
+    let mut operation = mlirBlockGetFirstOperation(block);
+
+    while !operation.ptr.is_null() {
+        match callback(OperationRef::from_raw(operation)) {
+            WalkResult::Advance => {
+                for index in 0..mlirOperationGetNumRegions(operation) {
+                    let mut block =
+                        mlirRegionGetFirstBlock(mlirOperationGetRegion(operation, index));
+
+                    while !block.ptr.is_null() {
+                        if walk_block(block, callback) == WalkResult::Interrupt {
+                            return WalkResult::Interrupt;
+                        }
+
+                        block = mlirBlockGetNextInRegion(block);
+                    }
+                }
+            }
+            WalkResult::Interrupt => return WalkResult::Interrupt,
+            WalkResult::Skip => {}
+        }
+
+        operation = mlirOperationGetNextInBlock(operation);
+    }
+
+    WalkResult::Advance
+}
+
+// Collects indices of operations in a block defining operands of an
+// operation or operations nested in it.
+unsafe fn collect_dependencies(
+    block: MlirBlock,
+    operation: MlirOperation,
+    indices: &HashMap<*mut c_void, usize>,
+    dependencies: &mut HashSet<usize>,
+) {
+    for index in 0..mlirOperationGetNumOperands(operation) {
+        let operand = mlirOperationGetOperand(operation, index);
+
+        if mlirValueIsABlockArgument(operand) {
+            continue;
+        }
+
+        let mut definition = mlirOpResultGetOwner(operand);
+
+        while !definition.ptr.is_null() {
+            let parent = mlirOperationGetBlock(definition);
+
+            if mlirBlockEqual(parent, block) {
+                if let Some(&index) = indices.get(&definition.ptr) {
+                    dependencies.insert(index);
+                }
+
+                break;
+            }
+
+            definition = mlirOperationGetParentOperation(definition);
+        }
+    }
+
+    for index in 0..mlirOperationGetNumRegions(operation) {
+        for nested in region_blocks(mlirOperationGetRegion(operation, index)) {
+            for operation in block_operations(nested) {
+                collect_dependencies(block, operation, indices, dependencies);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        ir::{operation::OperationBuilder, r#type::IntegerType, Module, Region, ValueLike},
+        ir::{
+            operation::{OperationBuilder, OperationPrintingFlags},
+            r#type::IntegerType,
+            Identifier, Module, Region,
+        },
         test::create_test_context,
     };
+    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -333,6 +606,25 @@ mod tests {
         Block::new(&[]);
     }
 
+    #[test]
+    fn new_with_argument_locations() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let region = Region::new();
+        region.append_block(Block::new(&[(
+            Type::index(&context),
+            Location::new(&context, "foo.mlir", 1, 2),
+        )]));
+
+        assert!(OperationBuilder::new("foo", Location::unknown(&context))
+            .add_region(region)
+            .build()
+            .to_string_with_flags(&OperationPrintingFlags::new().enable_debug_info(true, false))
+            .unwrap()
+            .contains("%arg0: index loc(\"foo.mlir\":1:2)"));
+    }
+
     #[test]
     fn argument() {
         let context = create_test_context();
@@ -359,6 +651,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arguments() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let types = [
+            IntegerType::new(&context, 64).into(),
+            Type::index(&context),
+            IntegerType::new(&context, 1).into(),
+        ];
+        let block = Block::new(&types.map(|r#type| (r#type, location)));
+
+        assert_eq!(block.argument_count(), 3);
+        assert_eq!(
+            block
+                .arguments()
+                .map(|argument| argument.r#type())
+                .collect::<Vec<_>>(),
+            types
+        );
+        assert!(matches!(
+            block.argument(3),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn argument_types() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let types = [Type::integer(&context, 32), Type::float64(&context)];
+        let block = Block::new(&types.map(|r#type| (r#type, location)));
+
+        assert_eq!(block.argument_types(), types);
+    }
+
     #[test]
     fn argument_count() {
         assert_eq!(Block::new(&[]).argument_count(), 0);
@@ -516,6 +843,133 @@ mod tests {
         );
     }
 
+    #[test]
+    fn append_operation_results() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let block = Block::new(&[]);
+        let r#type = Type::index(&context);
+        let integer_type = IntegerType::new(&context, 64).into();
+
+        let [a, b] = block
+            .append_operation_results(
+                OperationBuilder::new("foo", location)
+                    .add_results(&[r#type, integer_type])
+                    .build(),
+            )
+            .unwrap();
+
+        assert_eq!(a.r#type(), r#type);
+        assert_eq!(b.r#type(), integer_type);
+    }
+
+    #[test]
+    fn append_operation_results_with_wrong_count() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let block = Block::new(&[]);
+
+        assert_eq!(
+            block.append_operation_results::<2>(
+                OperationBuilder::new("foo", Location::unknown(&context)).build()
+            ),
+            Err(Error::ResultCountExpected {
+                expected: 2,
+                count: 0,
+                operation: "\"foo\"() : () -> ()\n".into(),
+            })
+        );
+        assert_eq!(block.first_operation(), None);
+    }
+
+    #[test]
+    fn split() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+
+        block.append_operation(OperationBuilder::new("foo", location).build());
+        let operation = block.append_operation(OperationBuilder::new("bar", location).build());
+        block.append_operation(OperationBuilder::new("baz", location).build());
+
+        let other = block.split(&operation);
+
+        assert_eq!(
+            block.first_operation().unwrap().name(),
+            Identifier::new(&context, "foo")
+        );
+        assert_eq!(block.first_operation().unwrap().next_in_block(), None);
+        assert_eq!(
+            other.first_operation().unwrap().name(),
+            Identifier::new(&context, "bar")
+        );
+        assert_eq!(
+            other
+                .first_operation()
+                .unwrap()
+                .next_in_block()
+                .unwrap()
+                .name(),
+            Identifier::new(&context, "baz")
+        );
+        assert_eq!(
+            other
+                .first_operation()
+                .unwrap()
+                .next_in_block()
+                .unwrap()
+                .next_in_block(),
+            None
+        );
+        assert_eq!(block.next_in_region(), Some(other));
+        assert_eq!(other.parent_region().as_deref(), Some(&region));
+    }
+
+    #[test]
+    fn topologically_sort() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument = block.argument(0).unwrap().into();
+
+        let add = block.append_operation(
+            OperationBuilder::new("arith.addi", location)
+                .add_operands(&[argument, argument])
+                .add_results(&[r#type])
+                .build(),
+        );
+        let multiply = block.insert_operation_before(
+            add,
+            OperationBuilder::new("arith.muli", location)
+                .add_operands(&[add.result(0).unwrap().into(), argument])
+                .add_results(&[r#type])
+                .build(),
+        );
+        block.append_operation(
+            OperationBuilder::new("func.return", location)
+                .add_operands(&[multiply.result(0).unwrap().into()])
+                .build(),
+        );
+
+        assert!(block.topologically_sort());
+
+        let names = [
+            block.first_operation().unwrap(),
+            block.first_operation().unwrap().next_in_block().unwrap(),
+            block.terminator().unwrap(),
+        ]
+        .map(|operation| operation.name());
+
+        assert_eq!(
+            names,
+            ["arith.addi", "arith.muli", "func.return"].map(|name| Identifier::new(&context, name))
+        );
+    }
+
     #[test]
     fn detach_detached() {
         let block = Block::new(&[]);
@@ -523,6 +977,18 @@ mod tests {
         assert!(unsafe { block.detach() }.is_none());
     }
 
+    #[test]
+    fn hash() {
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+        let other_block = region.append_block(Block::new(&[]));
+
+        let blocks = HashSet::from([block, other_block]);
+
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks.contains(&region.first_block().unwrap()));
+    }
+
     #[test]
     fn display() {
         assert_eq!(Block::new(&[]).to_string(), "<<UNLINKED BLOCK>>\n");
@@ -535,4 +1001,84 @@ mod tests {
             "Block(\n<<UNLINKED BLOCK>>\n)"
         );
     }
+
+    fn walk_names(walk_result: impl Fn(&str) -> WalkResult) -> (WalkResult, Vec<String>) {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                    %0 = arith.addi %arg0, %arg0 : i32
+                    return %0 : i32
+                }
+
+                func.func @bar() {
+                    return
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let mut names = vec![];
+
+        let result = module.body().walk(|operation| {
+            let name = operation
+                .name()
+                .as_string_ref()
+                .as_str()
+                .unwrap()
+                .to_owned();
+            let result = walk_result(&name);
+
+            names.push(name);
+
+            result
+        });
+
+        (result, names)
+    }
+
+    #[test]
+    fn walk() {
+        assert_eq!(
+            walk_names(|_| WalkResult::Advance),
+            (
+                WalkResult::Advance,
+                vec![
+                    "func.func".into(),
+                    "arith.addi".into(),
+                    "func.return".into(),
+                    "func.func".into(),
+                    "func.return".into(),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn walk_skip() {
+        assert_eq!(
+            walk_names(|_| WalkResult::Skip),
+            (
+                WalkResult::Advance,
+                vec!["func.func".into(), "func.func".into()]
+            )
+        );
+    }
+
+    #[test]
+    fn walk_interrupt() {
+        assert_eq!(
+            walk_names(|name| if name == "arith.addi" {
+                WalkResult::Interrupt
+            } else {
+                WalkResult::Advance
+            }),
+            (
+                WalkResult::Interrupt,
+                vec!["func.func".into(), "arith.addi".into()]
+            )
+        );
+    }
 }
@@ -0,0 +1,200 @@
+use super::{IrMapping, Operation, OperationRef, Region, Value, ValueLike};
+use crate::utility::{block_operations, region_blocks};
+use mlir_sys::{
+    mlirOperationDestroy, mlirOperationGetNumOperands, mlirOperationGetNumRegions,
+    mlirOperationGetOperand, mlirOperationGetRegion, mlirOperationGetResult,
+    mlirOperationSetOperand, MlirOperation, MlirRegion,
+};
+use std::{collections::HashMap, ffi::c_void};
+
+/// Eliminates common subexpressions in a region.
+///
+/// Operations are considered identical if they have the same content hash,
+/// name, attributes, and result types, and exactly the same operand values.
+/// Uses of results of later duplicates are replaced with results of the
+/// earliest operations and the duplicates are erased. Operations are only
+/// compared within the same block and operations with regions are never
+/// eliminated.
+///
+/// Side effects of operations are unknown to this function, so `is_pure`
+/// decides which operations are free of them and can be eliminated.
+///
+/// It returns the number of erased operations.
+pub fn eliminate_common_subexpressions(
+    region: &Region,
+    is_pure: impl Fn(&Operation) -> bool,
+) -> usize {
+    let mut mapping = IrMapping::new();
+    let mut duplicates = vec![];
+
+    unsafe {
+        eliminate_in_region(region.to_raw(), &is_pure, &mut mapping, &mut duplicates);
+
+        // Blocks are not necessarily ordered by dominance, so operands are
+        // remapped again once all duplicates are found.
+        for block in region_blocks(region.to_raw()) {
+            for operation in block_operations(block) {
+                mapping.remap_raw_operands(operation);
+            }
+        }
+
+        for &operation in duplicates.iter().rev() {
+            mlirOperationDestroy(operation);
+        }
+    }
+
+    duplicates.len()
+}
+
+unsafe fn eliminate_in_region(
+    region: MlirRegion,
+    is_pure: &impl Fn(&Operation) -> bool,
+    mapping: &mut IrMapping,
+    duplicates: &mut Vec<MlirOperation>,
+) {
+    for block in region_blocks(region) {
+        let mut operations = HashMap::<_, Vec<MlirOperation>>::new();
+
+        for raw in block_operations(block) {
+            remap_operands(raw, mapping);
+
+            for index in 0..mlirOperationGetNumRegions(raw) {
+                eliminate_in_region(
+                    mlirOperationGetRegion(raw, index),
+                    is_pure,
+                    mapping,
+                    duplicates,
+                );
+            }
+
+            let operation = OperationRef::from_raw(raw);
+
+            if operation.region_count() > 0 || !is_pure(&operation) {
+                continue;
+            }
+
+            let candidates = operations
+                .entry((
+                    operation.content_hash(),
+                    operation
+                        .operands()
+                        .map(|operand| operand.to_raw().ptr)
+                        .collect::<Vec<*const c_void>>(),
+                ))
+                .or_default();
+
+            if let Some(&original) = candidates
+                .iter()
+                .find(|&&other| equal_content(&operation, &OperationRef::from_raw(other)))
+            {
+                for index in 0..operation.result_count() {
+                    mapping.map_value(
+                        Value::from_raw(mlirOperationGetResult(raw, index as isize)),
+                        Value::from_raw(mlirOperationGetResult(original, index as isize)),
+                    );
+                }
+
+                duplicates.push(raw);
+            } else {
+                candidates.push(raw);
+            }
+        }
+    }
+}
+
+unsafe fn remap_operands(operation: MlirOperation, mapping: &IrMapping) {
+    for index in 0..mlirOperationGetNumOperands(operation) {
+        let operand = Value::from_raw(mlirOperationGetOperand(operation, index));
+
+        if let Some(value) = mapping.lookup_value(operand) {
+            mlirOperationSetOperand(operation, index, value.to_raw());
+        }
+    }
+}
+
+fn equal_content(one: &Operation, other: &Operation) -> bool {
+    one.name() == other.name()
+        && one.attributes().eq(other.attributes())
+        && one
+            .results()
+            .map(|result| result.r#type())
+            .eq(other.results().map(|result| result.r#type()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Module, test::create_test_context};
+    use indoc::indoc;
+
+    #[test]
+    fn eliminate_addi() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : index, %arg1 : index) -> index {
+                    %0 = arith.addi %arg0, %arg1 : index
+                    %1 = arith.addi %arg0, %arg1 : index
+                    %2 = arith.muli %0, %1 : index
+                    return %2 : index
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let function = module.body().first_operation().unwrap();
+
+        assert_eq!(
+            eliminate_common_subexpressions(&function.region(0).unwrap(), |operation| {
+                operation
+                    .name()
+                    .as_string_ref()
+                    .as_str()
+                    .unwrap()
+                    .starts_with("arith.")
+            }),
+            1
+        );
+        assert!(module.as_operation().verify());
+
+        let string = module.as_operation().to_string();
+
+        assert_eq!(string.matches("arith.addi").count(), 1);
+        assert!(string.contains("arith.muli %0, %0 : index"));
+    }
+
+    #[test]
+    fn keep_impure_operations() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : index, %arg1 : index) -> index {
+                    %0 = arith.addi %arg0, %arg1 : index
+                    %1 = arith.addi %arg0, %arg1 : index
+                    %2 = arith.muli %0, %1 : index
+                    return %2 : index
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let function = module.body().first_operation().unwrap();
+
+        assert_eq!(
+            eliminate_common_subexpressions(&function.region(0).unwrap(), |_| false),
+            0
+        );
+        assert_eq!(
+            module
+                .as_operation()
+                .to_string()
+                .matches("arith.addi")
+                .count(),
+            2
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use super::{OperationRef, Value, ValueLike};
+use crate::utility::region_blocks;
+use mlir_sys::{
+    mlirBlockArgumentGetOwner, mlirBlockEqual, mlirBlockGetParentOperation,
+    mlirBlockGetParentRegion, mlirBlockGetTerminator, mlirOpResultGetOwner, mlirOperationEqual,
+    mlirOperationGetBlock, mlirOperationGetNextInBlock, mlirOperationGetNumSuccessors,
+    mlirOperationGetSuccessor, mlirRegionEqual, mlirValueIsABlockArgument, MlirBlock,
+    MlirOperation, MlirRegion,
+};
+use std::{cell::RefCell, collections::HashMap, ffi::c_void};
+
+/// Dominance information.
+///
+/// Dominators of blocks are computed for each region when it is queried first
+/// and cached. Dominance
+/// information needs to be created again after control flow is modified.
+#[derive(Debug, Default)]
+pub struct Dominance {
+    regions: RefCell<HashMap<*mut c_void, RegionDominance>>,
+}
+
+#[derive(Debug)]
+struct RegionDominance {
+    indices: HashMap<*mut c_void, usize>,
+    // A block at each index is dominated by blocks at indices set to `true`.
+    dominators: Vec<Vec<bool>>,
+}
+
+impl Dominance {
+    /// Creates dominance information.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if a value dominates an operation using it.
+    ///
+    /// A result of an operation does not dominate the operation itself or
+    /// operations nested in it.
+    pub fn dominates(&self, value: Value, operation: OperationRef) -> bool {
+        unsafe {
+            let value = value.to_raw();
+            let (block, definition) = if mlirValueIsABlockArgument(value) {
+                (mlirBlockArgumentGetOwner(value), None)
+            } else {
+                let definition = mlirOpResultGetOwner(value);
+
+                (mlirOperationGetBlock(definition), Some(definition))
+            };
+
+            if block.ptr.is_null() {
+                return false;
+            }
+
+            let region = mlirBlockGetParentRegion(block);
+            let ancestor = match find_ancestor(operation.to_raw(), region) {
+                Some(ancestor) => ancestor,
+                None => return false,
+            };
+            let ancestor_block = mlirOperationGetBlock(ancestor);
+
+            if mlirBlockEqual(block, ancestor_block) {
+                definition.map_or(true, |definition| is_before(definition, ancestor))
+            } else {
+                self.block_dominates(region, block, ancestor_block)
+            }
+        }
+    }
+
+    unsafe fn block_dominates(&self, region: MlirRegion, one: MlirBlock, other: MlirBlock) -> bool {
+        if region.ptr.is_null() {
+            return false;
+        }
+
+        let mut regions = self.regions.borrow_mut();
+        let dominance = regions
+            .entry(region.ptr)
+            .or_insert_with(|| RegionDominance::new(region));
+
+        match (
+            dominance.indices.get(&one.ptr),
+            dominance.indices.get(&other.ptr),
+        ) {
+            (Some(&one), Some(&other)) => dominance.dominators[other][one],
+            _ => false,
+        }
+    }
+}
+
+impl RegionDominance {
+    unsafe fn new(region: MlirRegion) -> Self {
+        let blocks = region_blocks(region);
+        let indices = blocks
+            .iter()
+            .enumerate()
+            .map(|(index, block)| (block.ptr, index))
+            .collect::<HashMap<_, _>>();
+        let mut predecessors = vec![vec![]; blocks.len()];
+
+        for (index, &block) in blocks.iter().enumerate() {
+            let terminator = mlirBlockGetTerminator(block);
+
+            if terminator.ptr.is_null() {
+                continue;
+            }
+
+            for successor in 0..mlirOperationGetNumSuccessors(terminator) {
+                if let Some(&successor) =
+                    indices.get(&mlirOperationGetSuccessor(terminator, successor).ptr)
+                {
+                    predecessors[successor].push(index);
+                }
+            }
+        }
+
+        // Blocks are dominated by all blocks initially except for an entry
+        // block, and dominators are narrowed down until a fixed point.
+        let mut dominators = (0..blocks.len())
+            .map(|index| {
+                (0..blocks.len())
+                    .map(|other| index != 0 || other == 0)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for index in 1..blocks.len() {
+                if predecessors[index].is_empty() {
+                    continue;
+                }
+
+                let new = (0..blocks.len())
+                    .map(|other| {
+                        other == index
+                            || predecessors[index]
+                                .iter()
+                                .all(|&predecessor| dominators[predecessor][other])
+                    })
+                    .collect::<Vec<_>>();
+
+                if new != dominators[index] {
+                    dominators[index] = new;
+                    changed = true;
+                }
+            }
+        }
+
+        Self {
+            indices,
+            dominators,
+        }
+    }
+}
+
+// Finds an ancestor operation of an operation, including itself, in a region.
+unsafe fn find_ancestor(operation: MlirOperation, region: MlirRegion) -> Option<MlirOperation> {
+    let mut operation = operation;
+
+    loop {
+        let block = mlirOperationGetBlock(operation);
+
+        if block.ptr.is_null() {
+            return None;
+        } else if mlirRegionEqual(mlirBlockGetParentRegion(block), region) {
+            return Some(operation);
+        }
+
+        operation = mlirBlockGetParentOperation(block);
+
+        if operation.ptr.is_null() {
+            return None;
+        }
+    }
+}
+
+unsafe fn is_before(one: MlirOperation, other: MlirOperation) -> bool {
+    let mut operation = mlirOperationGetNextInBlock(one);
+
+    while !operation.ptr.is_null() {
+        if mlirOperationEqual(operation, other) {
+            return true;
+        }
+
+        operation = mlirOperationGetNextInBlock(operation);
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Module, test::create_test_context};
+    use indoc::indoc;
+
+    #[test]
+    fn dominates() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i1) -> index {
+                    %0 = arith.constant 0 : index
+                    cf.cond_br %arg0, ^bb1, ^bb2
+                ^bb1:
+                    %1 = arith.constant 1 : index
+                    cf.br ^bb3(%1 : index)
+                ^bb2:
+                    cf.br ^bb3(%0 : index)
+                ^bb3(%2 : index):
+                    return %2 : index
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let function = module.body().first_operation().unwrap();
+        let region = function.region(0).unwrap();
+        let entry = region.first_block().unwrap();
+        let first = entry.next_in_region().unwrap();
+        let second = first.next_in_region().unwrap();
+        let last = second.next_in_region().unwrap();
+
+        let zero_operation = entry.first_operation().unwrap();
+        let zero = zero_operation.result(0).unwrap().into();
+        let one_operation = first.first_operation().unwrap();
+        let one = one_operation.result(0).unwrap().into();
+        let argument = last.argument(0).unwrap().into();
+        let r#return = last.terminator().unwrap();
+
+        let dominance = Dominance::new();
+
+        assert!(dominance.dominates(zero, r#return));
+        assert!(dominance.dominates(zero, first.terminator().unwrap()));
+        assert!(dominance.dominates(zero, second.terminator().unwrap()));
+        assert!(!dominance.dominates(zero, zero_operation));
+        assert!(dominance.dominates(one, first.terminator().unwrap()));
+        assert!(!dominance.dominates(one, r#return));
+        assert!(!dominance.dominates(one, second.terminator().unwrap()));
+        assert!(dominance.dominates(argument, r#return));
+    }
+}
@@ -76,6 +76,17 @@ mod tests {
         Identifier::new(&Context::new(), "foo").context();
     }
 
+    #[test]
+    fn context_for_another_identifier() {
+        let context = Context::new();
+        let identifier = Identifier::new(&context, "foo");
+
+        assert_eq!(
+            Identifier::new(&identifier.context(), "bar"),
+            Identifier::new(&context, "bar")
+        );
+    }
+
     #[test]
     fn equal() {
         let context = Context::new();
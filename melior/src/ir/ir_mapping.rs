@@ -0,0 +1,178 @@
+use super::{Block, BlockRef, Value, ValueLike};
+use crate::utility::{block_operations, region_blocks};
+use mlir_sys::{
+    mlirIdentifierStr, mlirOperationClone, mlirOperationCreate, mlirOperationGetAttribute,
+    mlirOperationGetLocation, mlirOperationGetName, mlirOperationGetNumAttributes,
+    mlirOperationGetNumOperands, mlirOperationGetNumRegions, mlirOperationGetNumResults,
+    mlirOperationGetNumSuccessors, mlirOperationGetOperand, mlirOperationGetRegion,
+    mlirOperationGetResult, mlirOperationGetSuccessor, mlirOperationSetOperand,
+    mlirOperationStateAddAttributes, mlirOperationStateAddOperands, mlirOperationStateAddResults,
+    mlirOperationStateAddSuccessors, mlirOperationStateGet, mlirValueGetType, MlirBlock,
+    MlirOperation, MlirValue,
+};
+use std::{collections::HashMap, ffi::c_void, marker::PhantomData};
+
+/// A mapping of values and blocks used to clone IR.
+///
+/// The C API does not expose `IRMapping`, so mappings are kept on the Rust
+/// side and applied to operands and successors of cloned operations.
+#[derive(Debug, Default)]
+pub struct IrMapping<'c, 'a> {
+    values: HashMap<*const c_void, MlirValue>,
+    blocks: HashMap<*mut c_void, MlirBlock>,
+    _parent: PhantomData<Value<'c, 'a>>,
+}
+
+impl<'c, 'a> IrMapping<'c, 'a> {
+    /// Creates an empty mapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps a value to another value.
+    pub fn map_value(&mut self, from: Value<'c, 'a>, to: Value<'c, 'a>) {
+        self.values.insert(from.to_raw().ptr, to.to_raw());
+    }
+
+    /// Maps a block to another block.
+    pub fn map_block(&mut self, from: &'a Block<'c>, to: &'a Block<'c>) {
+        self.blocks.insert(from.to_raw().ptr, to.to_raw());
+    }
+
+    /// Looks up a value mapped from a value.
+    pub fn lookup_value(&self, from: Value<'c, 'a>) -> Option<Value<'c, 'a>> {
+        self.values
+            .get(&from.to_raw().ptr)
+            .map(|&value| unsafe { Value::from_raw(value) })
+    }
+
+    /// Looks up a block mapped from a block.
+    pub fn lookup_block(&self, from: &Block<'c>) -> Option<BlockRef<'c, 'a>> {
+        self.blocks
+            .get(&from.to_raw().ptr)
+            .map(|&block| unsafe { BlockRef::from_raw(block) })
+    }
+
+    /// Clones an operation with its successors mapped.
+    ///
+    /// Operations with successors are recreated instead of cloned because
+    /// their successors cannot be changed after creation. Operands are not
+    /// remapped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operation has both successors and regions.
+    pub(crate) unsafe fn clone_raw_operation(&self, operation: MlirOperation) -> MlirOperation {
+        if mlirOperationGetNumSuccessors(operation) == 0 {
+            return mlirOperationClone(operation);
+        }
+
+        assert_eq!(
+            mlirOperationGetNumRegions(operation),
+            0,
+            "operations with both successors and regions cannot be cloned"
+        );
+
+        let results = (0..mlirOperationGetNumResults(operation))
+            .map(|index| mlirValueGetType(mlirOperationGetResult(operation, index)))
+            .collect::<Vec<_>>();
+        let operands = (0..mlirOperationGetNumOperands(operation))
+            .map(|index| mlirOperationGetOperand(operation, index))
+            .collect::<Vec<_>>();
+        let successors = (0..mlirOperationGetNumSuccessors(operation))
+            .map(|index| {
+                let successor = mlirOperationGetSuccessor(operation, index);
+
+                self.blocks
+                    .get(&successor.ptr)
+                    .copied()
+                    .unwrap_or(successor)
+            })
+            .collect::<Vec<_>>();
+        let attributes = (0..mlirOperationGetNumAttributes(operation))
+            .map(|index| mlirOperationGetAttribute(operation, index))
+            .collect::<Vec<_>>();
+
+        let mut state = mlirOperationStateGet(
+            mlirIdentifierStr(mlirOperationGetName(operation)),
+            mlirOperationGetLocation(operation),
+        );
+
+        mlirOperationStateAddResults(&mut state, results.len() as isize, results.as_ptr());
+        mlirOperationStateAddOperands(&mut state, operands.len() as isize, operands.as_ptr());
+        mlirOperationStateAddSuccessors(&mut state, successors.len() as isize, successors.as_ptr());
+        mlirOperationStateAddAttributes(&mut state, attributes.len() as isize, attributes.as_ptr());
+
+        mlirOperationCreate(&mut state)
+    }
+
+    /// Remaps operands of an operation and operations nested in it.
+    pub(crate) unsafe fn remap_raw_operands(&self, operation: MlirOperation) {
+        for index in 0..mlirOperationGetNumOperands(operation) {
+            let operand = mlirOperationGetOperand(operation, index);
+
+            if let Some(&value) = self.values.get(&operand.ptr) {
+                mlirOperationSetOperand(operation, index, value);
+            }
+        }
+
+        for index in 0..mlirOperationGetNumRegions(operation) {
+            for block in region_blocks(mlirOperationGetRegion(operation, index)) {
+                for operation in block_operations(block) {
+                    self.remap_raw_operands(operation);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Location, Type},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn new() {
+        IrMapping::new();
+    }
+
+    #[test]
+    fn map_value() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let block = Block::new(&[
+            (Type::index(&context), location),
+            (Type::index(&context), location),
+        ]);
+        let from = Value::from(block.argument(0).unwrap());
+        let to = Value::from(block.argument(1).unwrap());
+        let mut mapping = IrMapping::new();
+
+        assert_eq!(mapping.lookup_value(from), None);
+
+        mapping.map_value(from, to);
+
+        assert_eq!(mapping.lookup_value(from), Some(to));
+        assert_eq!(mapping.lookup_value(to), None);
+    }
+
+    #[test]
+    fn map_block() {
+        let from = Block::new(&[]);
+        let to = Block::new(&[]);
+        let mut mapping = IrMapping::new();
+
+        assert_eq!(mapping.lookup_block(&from), None);
+
+        mapping.map_block(&from, &to);
+
+        assert_eq!(
+            mapping.lookup_block(&from),
+            Some(unsafe { BlockRef::from_raw(to.to_raw()) })
+        );
+        assert_eq!(mapping.lookup_block(&to), None);
+    }
+}
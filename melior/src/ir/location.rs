@@ -5,15 +5,21 @@ use crate::{
     utility::print_callback,
 };
 use mlir_sys::{
-    mlirLocationEqual, mlirLocationFileLineColGet, mlirLocationFusedGet, mlirLocationGetContext,
-    mlirLocationNameGet, mlirLocationPrint, mlirLocationUnknownGet, MlirLocation,
+    mlirEmitError, mlirLocationEqual, mlirLocationFileLineColGet, mlirLocationFusedGet,
+    mlirLocationGetContext, mlirLocationNameGet, mlirLocationPrint, mlirLocationUnknownGet,
+    MlirLocation,
 };
+#[cfg(feature = "strict-locations")]
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{
-    ffi::c_void,
+    ffi::{c_void, CString},
     fmt::{self, Display, Formatter},
     marker::PhantomData,
 };
 
+#[cfg(feature = "strict-locations")]
+static UNKNOWN_LOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
 /// A location
 #[derive(Clone, Copy, Debug)]
 pub struct Location<'c> {
@@ -58,15 +64,41 @@ impl<'c> Location<'c> {
     }
 
     /// Creates an unknown location.
+    ///
+    /// With the `strict-locations` feature, every call is counted so that
+    /// overuse of unknown locations can be detected with
+    /// [`unknown_count`](Self::unknown_count).
     pub fn unknown(context: &'c Context) -> Self {
+        #[cfg(feature = "strict-locations")]
+        UNKNOWN_LOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+
         unsafe { Self::from_raw(mlirLocationUnknownGet(context.to_raw())) }
     }
 
+    /// Gets a number of unknown locations created in a process.
+    #[cfg(feature = "strict-locations")]
+    pub fn unknown_count() -> usize {
+        UNKNOWN_LOCATION_COUNT.load(Ordering::Relaxed)
+    }
+
     /// Gets a context.
     pub fn context(&self) -> ContextRef<'c> {
         unsafe { ContextRef::from_raw(mlirLocationGetContext(self.raw)) }
     }
 
+    /// Emits an error diagnostic at a location.
+    ///
+    /// The diagnostic is passed to diagnostic handlers attached to a context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a message contains a null byte.
+    pub fn emit_error(&self, message: &str) {
+        let message = CString::new(message).expect("message without null bytes");
+
+        unsafe { mlirEmitError(self.raw, message.as_ptr()) }
+    }
+
     /// Creates a location from a raw object.
     ///
     /// # Safety
@@ -117,6 +149,18 @@ mod tests {
         Location::new(&Context::new(), "foo", 42, 42);
     }
 
+    #[test]
+    fn emit_error() {
+        let context = Context::new();
+        let location = Location::name(&context, "foo", Location::new(&context, "bar", 1, 2));
+
+        let ((), diagnostics) = context.with_captured_diagnostics(|| location.emit_error("baz"));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message(), "baz");
+        assert_eq!(diagnostics[0].location(), location.to_string());
+    }
+
     #[test]
     fn fused() {
         let context = Context::new();
@@ -143,6 +187,16 @@ mod tests {
         Location::unknown(&Context::new());
     }
 
+    #[cfg(feature = "strict-locations")]
+    #[test]
+    fn unknown_count() {
+        let count = Location::unknown_count();
+
+        Location::unknown(&Context::new());
+
+        assert!(Location::unknown_count() > count);
+    }
+
     #[test]
     fn context() {
         Location::new(&Context::new(), "foo", 42, 42).context();
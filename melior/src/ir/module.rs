@@ -1,11 +1,13 @@
-use super::{BlockRef, Location, Operation, OperationRef};
+use super::{attribute::StringAttribute, BlockRef, Location, Operation, OperationRef};
 use crate::{
     context::{Context, ContextRef},
     string_ref::StringRef,
+    Error,
 };
 use mlir_sys::{
-    mlirModuleCreateEmpty, mlirModuleCreateParse, mlirModuleDestroy, mlirModuleFromOperation,
-    mlirModuleGetBody, mlirModuleGetContext, mlirModuleGetOperation, MlirModule,
+    mlirBlockAppendOwnedOperation, mlirModuleCreateEmpty, mlirModuleCreateParse, mlirModuleDestroy,
+    mlirModuleFromOperation, mlirModuleGetBody, mlirModuleGetContext, mlirModuleGetOperation,
+    mlirOperationRemoveFromParent, MlirModule,
 };
 use std::marker::PhantomData;
 
@@ -48,6 +50,50 @@ impl<'c> Module<'c> {
         unsafe { BlockRef::from_raw(mlirModuleGetBody(self.raw)) }
     }
 
+    /// Moves all operations in a body of another module into this module.
+    ///
+    /// It returns an error without moving any operations if symbol names of
+    /// the operations collide.
+    pub fn append_operations_from(&mut self, other: Module<'c>) -> Result<(), Error> {
+        let symbols = self.symbols();
+
+        if let Some(symbol) = other
+            .symbols()
+            .into_iter()
+            .find(|symbol| symbols.contains(symbol))
+        {
+            return Err(Error::DuplicateSymbol(symbol));
+        }
+
+        while let Some(operation) = other.body().first_operation() {
+            unsafe {
+                mlirOperationRemoveFromParent(operation.to_raw());
+                mlirBlockAppendOwnedOperation(self.body().to_raw(), operation.to_raw());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn symbols(&self) -> Vec<String> {
+        let mut symbols = vec![];
+        let mut operation = self.body().first_operation();
+
+        while let Some(current) = operation {
+            if let Some(symbol) = current
+                .attribute("sym_name")
+                .ok()
+                .and_then(|attribute| StringAttribute::try_from(attribute).ok())
+            {
+                symbols.push(symbol.value().as_str().unwrap_or_default().into());
+            }
+
+            operation = current.next_in_block();
+        }
+
+        symbols
+    }
+
     /// Converts an operation into a module.
     pub fn from_operation(operation: Operation) -> Option<Self> {
         unsafe { Self::from_option_raw(mlirModuleFromOperation(operation.into_raw())) }
@@ -94,9 +140,10 @@ impl<'c> Drop for Module<'c> {
 mod tests {
     use super::*;
     use crate::{
-        ir::{operation::OperationBuilder, Block, Region},
+        ir::{operation::OperationBuilder, Block, Region, Type},
         test::create_test_context,
     };
+    use indoc::indoc;
 
     #[test]
     fn new() {
@@ -108,6 +155,15 @@ mod tests {
         Module::new(Location::new(&Context::new(), "foo", 42, 42)).context();
     }
 
+    #[test]
+    fn context_of_parsed_module() {
+        let context = create_test_context();
+        let module = Module::parse(&context, "module{}").unwrap();
+        let module_context = module.context();
+
+        assert_eq!(Type::index(&module_context), Type::index(&context));
+    }
+
     #[test]
     fn parse() {
         assert!(Module::parse(&Context::new(), "module{}").is_some());
@@ -118,6 +174,50 @@ mod tests {
         assert!(Module::parse(&Context::new(), "module{").is_none());
     }
 
+    #[test]
+    fn append_operations_from() {
+        let context = create_test_context();
+        let mut module = Module::parse(&context, "func.func @foo() { return }").unwrap();
+
+        module
+            .append_operations_from(Module::parse(&context, "func.func @bar() { return }").unwrap())
+            .unwrap();
+
+        assert!(module.as_operation().verify());
+        assert_eq!(
+            module.as_operation().to_string(),
+            indoc!(
+                "
+                module {
+                  func.func @foo() {
+                    return
+                  }
+                  func.func @bar() {
+                    return
+                  }
+                }
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn append_operations_from_with_duplicate_symbol() {
+        let context = create_test_context();
+        let mut module = Module::parse(&context, "func.func @foo() { return }").unwrap();
+
+        assert_eq!(
+            module.append_operations_from(
+                Module::parse(&context, "func.func @foo() { return }").unwrap()
+            ),
+            Err(Error::DuplicateSymbol("foo".into()))
+        );
+        assert_eq!(
+            module.body().first_operation().unwrap().next_in_block(),
+            None
+        );
+    }
+
     #[test]
     fn from_operation() {
         let context = create_test_context();
@@ -1,16 +1,33 @@
 //! Operations and operation builders.
 
 mod builder;
+mod name;
+mod node;
+mod operand;
 mod printing_flags;
 mod result;
+mod verification_report;
+mod walk_result;
 
 pub use self::{
-    builder::OperationBuilder, printing_flags::OperationPrintingFlags, result::OperationResult,
+    builder::OperationBuilder,
+    name::OperationName,
+    node::{BlockNode, OperationNode, RegionNode},
+    operand::OperationOperand,
+    printing_flags::OperationPrintingFlags,
+    result::OperationResult,
+    verification_report::VerificationReport,
+    walk_result::WalkResult,
+};
+use super::{
+    attribute::{StringAttribute, TypeAttribute},
+    r#type::FunctionType,
+    Attribute, AttributeLike, BlockRef, Identifier, IrMapping, Location, RegionRef, TypeLike,
+    Value, ValueLike,
 };
-use super::{Attribute, AttributeLike, BlockRef, Identifier, RegionRef, Value};
 use crate::{
     context::{Context, ContextRef},
-    utility::{print_callback, print_string_callback},
+    utility::{print_bytes_callback, print_callback, print_string_callback, wrap_lines},
     Error, StringRef,
 };
 use core::{
@@ -18,18 +35,22 @@ use core::{
     mem::{forget, transmute},
 };
 use mlir_sys::{
-    mlirOperationClone, mlirOperationDestroy, mlirOperationDump, mlirOperationEqual,
-    mlirOperationGetAttribute, mlirOperationGetAttributeByName, mlirOperationGetBlock,
-    mlirOperationGetContext, mlirOperationGetName, mlirOperationGetNextInBlock,
+    mlirContextIsRegisteredOperation, mlirIdentifierStr, mlirOperationClone, mlirOperationDestroy,
+    mlirOperationDump, mlirOperationEqual, mlirOperationGetAttribute,
+    mlirOperationGetAttributeByName, mlirOperationGetBlock, mlirOperationGetContext,
+    mlirOperationGetLocation, mlirOperationGetName, mlirOperationGetNextInBlock,
     mlirOperationGetNumAttributes, mlirOperationGetNumOperands, mlirOperationGetNumRegions,
     mlirOperationGetNumResults, mlirOperationGetNumSuccessors, mlirOperationGetOperand,
-    mlirOperationGetRegion, mlirOperationGetResult, mlirOperationGetSuccessor, mlirOperationPrint,
-    mlirOperationPrintWithFlags, mlirOperationRemoveAttributeByName,
-    mlirOperationSetAttributeByName, mlirOperationVerify, MlirOperation,
+    mlirOperationGetParentOperation, mlirOperationGetRegion, mlirOperationGetResult,
+    mlirOperationGetSuccessor, mlirOperationPrint, mlirOperationPrintWithFlags,
+    mlirOperationRemoveAttributeByName, mlirOperationSetAttributeByName, mlirOperationSetOperand,
+    mlirOperationVerify, MlirOperation,
 };
 use std::{
+    collections::hash_map::DefaultHasher,
     ffi::c_void,
     fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
     marker::PhantomData,
     ops::Deref,
 };
@@ -51,6 +72,35 @@ impl<'c> Operation<'c> {
         unsafe { Identifier::from_raw(mlirOperationGetName(self.raw)) }
     }
 
+    /// Returns `true` if an operation is registered in its context.
+    ///
+    /// Operations of unregistered dialects or with misspelled names are
+    /// opaque and not registered.
+    pub fn is_registered(&self) -> bool {
+        unsafe {
+            mlirContextIsRegisteredOperation(
+                mlirOperationGetContext(self.raw),
+                mlirIdentifierStr(mlirOperationGetName(self.raw)),
+            )
+        }
+    }
+
+    /// Gets a location.
+    pub fn location(&self) -> Location<'c> {
+        unsafe { Location::from_raw(mlirOperationGetLocation(self.raw)) }
+    }
+
+    /// Emits an error diagnostic at a location of an operation.
+    ///
+    /// The diagnostic is passed to diagnostic handlers attached to a context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a message contains a null byte.
+    pub fn emit_error(&self, message: &str) {
+        self.location().emit_error(message)
+    }
+
     /// Gets a block.
     // TODO Store lifetime of block in operations, or create another type like
     // `AppendedOperationRef`?
@@ -58,6 +108,11 @@ impl<'c> Operation<'c> {
         unsafe { BlockRef::from_option_raw(mlirOperationGetBlock(self.raw)) }
     }
 
+    /// Gets a parent operation.
+    pub fn parent_operation(&self) -> Option<OperationRef<'c, '_>> {
+        unsafe { OperationRef::from_option_raw(mlirOperationGetParentOperation(self.raw)) }
+    }
+
     /// Gets the number of operands.
     pub fn operand_count(&self) -> usize {
         unsafe { mlirOperationGetNumOperands(self.raw) as usize }
@@ -81,6 +136,34 @@ impl<'c> Operation<'c> {
         (0..self.operand_count()).map(|index| self.operand(index).expect("valid operand index"))
     }
 
+    /// Sets an operand at a position.
+    pub fn set_operand(&mut self, index: usize, value: Value<'c, '_>) -> Result<(), Error> {
+        if index < self.operand_count() {
+            unsafe { mlirOperationSetOperand(self.raw, index as isize, value.to_raw()) }
+
+            Ok(())
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "operation operand",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Remaps operands with a function.
+    ///
+    /// Operands are replaced only where the function returns `Some`.
+    pub fn remap_operands<'a>(&'a mut self, map: &dyn Fn(Value<'c, '_>) -> Option<Value<'c, 'a>>) {
+        for index in 0..self.operand_count() {
+            let operand = self.operand(index).expect("valid operand index");
+
+            if let Some(value) = map(operand) {
+                self.set_operand(index, value).expect("valid operand index");
+            }
+        }
+    }
+
     /// Gets the number of results.
     pub fn result_count(&self) -> usize {
         unsafe { mlirOperationGetNumResults(self.raw) as usize }
@@ -101,6 +184,17 @@ impl<'c> Operation<'c> {
         }
     }
 
+    /// Gets a result of an operation with exactly one result.
+    pub fn single_result(&self) -> Result<OperationResult<'c, '_>, Error> {
+        match self.result_count() {
+            1 => self.result(0),
+            count => Err(Error::SingleResultExpected {
+                count,
+                operation: self.to_string(),
+            }),
+        }
+    }
+
     /// Gets all results.
     pub fn results(&self) -> impl Iterator<Item = OperationResult<'c, '_>> {
         (0..self.result_count()).map(|index| self.result(index).expect("valid result index"))
@@ -129,6 +223,35 @@ impl<'c> Operation<'c> {
         (0..self.region_count()).map(|index| self.region(index).expect("valid result index"))
     }
 
+    /// Gets a function type of a function-like operation from its
+    /// `function_type` attribute.
+    ///
+    /// Operations with function types of other dialects, such as `llvm.func`,
+    /// return `None`.
+    pub fn function_type(&self) -> Option<FunctionType<'c>> {
+        TypeAttribute::try_from(self.attribute("function_type").ok()?)
+            .ok()?
+            .value()
+            .try_into()
+            .ok()
+    }
+
+    /// Gets a body region of a function-like operation.
+    pub fn function_body(&self) -> Option<RegionRef<'c, '_>> {
+        if self.has_attribute("function_type") {
+            self.region(0).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Gets a name of a function-like operation from its `sym_name` attribute.
+    pub fn function_name(&self) -> Option<&str> {
+        let attribute = StringAttribute::try_from(self.attribute("sym_name").ok()?).ok()?;
+
+        attribute.value().as_str().ok()
+    }
+
     /// Gets the number of successors.
     pub fn successor_count(&self) -> usize {
         unsafe { mlirOperationGetNumSuccessors(self.raw) as usize }
@@ -153,6 +276,34 @@ impl<'c> Operation<'c> {
             .map(|index| self.successor(index).expect("valid successor index"))
     }
 
+    /// Returns `true` if an operation has no operands.
+    pub fn has_no_operands(&self) -> bool {
+        self.operand_count() == 0
+    }
+
+    /// Returns `true` if an operation has no results.
+    pub fn has_no_results(&self) -> bool {
+        self.result_count() == 0
+    }
+
+    /// Returns `true` if an operation has no regions.
+    pub fn has_no_regions(&self) -> bool {
+        self.region_count() == 0
+    }
+
+    /// Returns `true` if an operation is a terminator.
+    ///
+    /// An operation outside of a block is considered a terminator only if it
+    /// has successors.
+    pub fn is_terminator(&self) -> bool {
+        match self.block() {
+            Some(block) => block
+                .terminator()
+                .map_or(false, |terminator| &*terminator == self),
+            None => self.successor_count() > 0,
+        }
+    }
+
     /// Gets the number of attributes.
     pub fn attribute_count(&self) -> usize {
         unsafe { mlirOperationGetNumAttributes(self.raw) as usize }
@@ -230,18 +381,60 @@ impl<'c> Operation<'c> {
         }
     }
 
+    /// Clones an operation with uses of values and blocks replaced through a
+    /// mapping.
+    ///
+    /// Nested regions are cloned as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operation has both successors and regions.
+    pub fn clone_with_mapping(&self, mapping: &IrMapping<'c, '_>) -> Self {
+        unsafe {
+            let operation = mapping.clone_raw_operation(self.raw);
+
+            mapping.remap_raw_operands(operation);
+
+            Self::from_raw(operation)
+        }
+    }
+
     /// Verifies an operation.
+    ///
+    /// Operations nested in regions of the operation are verified as well.
     pub fn verify(&self) -> bool {
         unsafe { mlirOperationVerify(self.raw) }
     }
 
+    /// Verifies an operation and all operations nested in it recursively.
+    ///
+    /// This is the same as [`verify`](Self::verify) but makes the recursion
+    /// explicit. The C API does not expose verification of a single operation
+    /// without its nested operations.
+    pub fn verify_all(&self) -> bool {
+        self.verify()
+    }
+
+    /// Verifies an operation and captures diagnostics emitted on failure.
+    pub fn verify_and_report(&self) -> VerificationReport {
+        let (valid, diagnostics) = self.context().with_captured_diagnostics(|| self.verify());
+
+        VerificationReport::new(
+            valid,
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.message().into())
+                .collect(),
+        )
+    }
+
     /// Dumps an operation.
     pub fn dump(&self) {
         unsafe { mlirOperationDump(self.raw) }
     }
 
     /// Prints an operation with flags.
-    pub fn to_string_with_flags(&self, flags: OperationPrintingFlags) -> Result<String, Error> {
+    pub fn to_string_with_flags(&self, flags: &OperationPrintingFlags) -> Result<String, Error> {
         let mut data = (String::new(), Ok::<_, Error>(()));
 
         unsafe {
@@ -258,6 +451,99 @@ impl<'c> Operation<'c> {
         Ok(data.0)
     }
 
+    /// Prints an operation in its local scope.
+    ///
+    /// This is useful to print operations nested in others without their
+    /// parents. Operations are not assumed to be verified because MLIR 16
+    /// does not expose the printing flag for it.
+    pub fn to_string_local(&self) -> Result<String, Error> {
+        self.to_string_with_flags(&OperationPrintingFlags::new().use_local_scope())
+    }
+
+    /// Prints an operation with lines wrapped at a width.
+    ///
+    /// MLIR has no printing option for line widths, so printed lines are
+    /// wrapped afterwards at spaces outside of string literals.
+    pub fn to_string_wrapped(&self, width: usize) -> String {
+        wrap_lines(&self.to_string(), width)
+    }
+
+    /// Converts an operation into a structured representation.
+    ///
+    /// Attributes and types are printed as strings.
+    pub fn to_structured(&self) -> OperationNode {
+        OperationNode::new(self)
+    }
+
+    /// Returns `true` if two operations are structurally equal ignoring
+    /// their locations.
+    ///
+    /// Operations are compared by their generic forms printed without debug
+    /// information, so locations of nested operations and block arguments
+    /// are ignored as well.
+    pub fn structurally_equal_ignoring_location(&self, other: &Operation) -> bool {
+        let flags = OperationPrintingFlags::new()
+            .enable_debug_info(false, false)
+            .print_generic_operation_form()
+            .use_local_scope();
+
+        match (
+            self.to_string_with_flags(&flags),
+            other.to_string_with_flags(&flags),
+        ) {
+            (Ok(one), Ok(other)) => one == other,
+            _ => false,
+        }
+    }
+
+    /// Computes a content hash of an operation.
+    ///
+    /// The hash covers a name, attributes, operand types, and result types
+    /// but not identities of operand values, regions, or locations. It is a
+    /// heuristic for finding candidates of structurally identical operations
+    /// rather than a collision-free canonical hash.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        // Identifiers, attributes, and types are uniqued in a context.
+        self.name().to_raw().ptr.hash(&mut hasher);
+
+        for (name, attribute) in self.attributes() {
+            name.to_raw().ptr.hash(&mut hasher);
+            attribute.to_raw().ptr.hash(&mut hasher);
+        }
+
+        for operand in self.operands() {
+            operand.r#type().to_raw().ptr.hash(&mut hasher);
+        }
+
+        self.result_count().hash(&mut hasher);
+
+        for result in self.results() {
+            result.r#type().to_raw().ptr.hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    /// Converts an operation into bytes.
+    ///
+    /// Unlike its `Display` implementation, which fails on non-UTF-8 output,
+    /// this function returns printed bytes as they are.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+
+        unsafe {
+            mlirOperationPrint(
+                self.raw,
+                Some(print_bytes_callback),
+                &mut bytes as *mut _ as *mut c_void,
+            );
+        }
+
+        bytes
+    }
+
     /// Creates an operation from a raw object.
     ///
     /// # Safety
@@ -270,6 +556,19 @@ impl<'c> Operation<'c> {
         }
     }
 
+    /// Creates an optional operation from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(raw: MlirOperation) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+
     /// Converts an operation into a raw object.
     pub fn into_raw(self) -> MlirOperation {
         let operation = self.raw;
@@ -332,6 +631,11 @@ pub struct OperationRef<'c, 'a> {
 }
 
 impl<'c, 'a> OperationRef<'c, 'a> {
+    /// Gets a context.
+    pub fn context(self) -> ContextRef<'c> {
+        unsafe { self.to_ref() }.context()
+    }
+
     /// Gets a result at a position.
     pub fn result(self, index: usize) -> Result<OperationResult<'c, 'a>, Error> {
         unsafe { self.to_ref() }.result(index)
@@ -353,6 +657,16 @@ impl<'c, 'a> OperationRef<'c, 'a> {
         transmute(self)
     }
 
+    /// Gets an operation borrowed for the lifetime of an operation reference.
+    ///
+    /// Unlike [`to_ref`](Self::to_ref), this is safe because the returned
+    /// reference cannot outlive the operation reference.
+    pub fn as_operation(&self) -> &Operation<'c> {
+        // `Operation` and `OperationRef` have the same layout and the
+        // lifetime of the returned reference is bound to `self`.
+        unsafe { &*(self as *const Self as *const Operation<'c>) }
+    }
+
     /// Converts an operation reference into a raw object.
     pub const fn to_raw(self) -> MlirOperation {
         self.raw
@@ -417,9 +731,12 @@ mod tests {
     use super::*;
     use crate::{
         context::Context,
-        ir::{attribute::StringAttribute, Block, Location, Region, Type},
+        diagnostic::DiagnosticSeverity,
+        dialect::{arith, func},
+        ir::{attribute::StringAttribute, Block, Location, Module, Region, Type},
         test::create_test_context,
     };
+    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -429,6 +746,20 @@ mod tests {
         OperationBuilder::new("foo", Location::unknown(&context)).build();
     }
 
+    #[test]
+    fn context_from_reference() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let block = Block::new(&[]);
+        let operation = block
+            .append_operation(OperationBuilder::new("foo", Location::unknown(&context)).build());
+
+        assert_eq!(
+            Identifier::new(&operation.context(), "bar"),
+            Identifier::new(&context, "bar")
+        );
+    }
+
     #[test]
     fn name() {
         let context = Context::new();
@@ -442,6 +773,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_registered() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+
+        assert!(OperationBuilder::new("func.return", location)
+            .build()
+            .is_registered());
+        assert!(!OperationBuilder::new("func.retrun", location)
+            .build()
+            .is_registered());
+    }
+
+    #[test]
+    fn as_operation() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let block = Block::new(&[]);
+        let operation = block
+            .append_operation(OperationBuilder::new("foo", Location::unknown(&context)).build());
+
+        assert_eq!(
+            operation.as_operation().name(),
+            Identifier::new(&context, "foo")
+        );
+    }
+
     #[test]
     fn block() {
         let context = create_test_context();
@@ -453,6 +812,19 @@ mod tests {
         assert_eq!(operation.block().as_deref(), Some(&block));
     }
 
+    #[test]
+    fn parent_operation() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let module = Module::new(Location::unknown(&context));
+        let operation = module
+            .body()
+            .append_operation(OperationBuilder::new("foo", Location::unknown(&context)).build());
+
+        assert_eq!(operation.parent_operation(), Some(module.as_operation()));
+        assert_eq!(module.as_operation().parent_operation(), None);
+    }
+
     #[test]
     fn block_none() {
         let context = create_test_context();
@@ -566,6 +938,42 @@ mod tests {
         )
     }
 
+    #[test]
+    fn attributes() {
+        let context = create_test_context();
+
+        let operation = OperationBuilder::new("arith.constant", Location::unknown(&context))
+            .add_results(&[Type::index(&context)])
+            .add_attributes(&[(
+                Identifier::new(&context, "value"),
+                Attribute::parse(&context, "42 : index").unwrap(),
+            )])
+            .build();
+
+        let attributes = operation.attributes().collect::<Vec<_>>();
+
+        assert_eq!(
+            attributes,
+            vec![(
+                Identifier::new(&context, "value"),
+                Attribute::parse(&context, "42 : index").unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn unit_attribute() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_attributes(&[(Identifier::new(&context, "bar"), Attribute::unit(&context))])
+            .build();
+
+        assert!(operation.attribute("bar").unwrap().is_unit());
+        assert_eq!(operation.to_string(), "\"foo\"() {bar} : () -> ()\n");
+    }
+
     #[test]
     fn clone() {
         let context = create_test_context();
@@ -588,6 +996,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_bytes() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        assert_eq!(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .add_attributes(&[(
+                    Identifier::new(&context, "bar"),
+                    StringAttribute::new(&context, "a\0b").into(),
+                )])
+                .build()
+                .to_bytes(),
+            b"\"foo\"() {bar = \"a\\00b\"} : () -> ()\n"
+        );
+    }
+
     #[test]
     fn debug() {
         let context = create_test_context();
@@ -611,7 +1036,7 @@ mod tests {
             OperationBuilder::new("foo", Location::unknown(&context))
                 .build()
                 .to_string_with_flags(
-                    OperationPrintingFlags::new()
+                    &OperationPrintingFlags::new()
                         .elide_large_elements_attributes(100)
                         .enable_debug_info(true, true)
                         .print_generic_operation_form()
@@ -620,4 +1045,391 @@ mod tests {
             Ok("\"foo\"() : () -> () [unknown]".into())
         );
     }
+
+    #[test]
+    fn structurally_equal_ignoring_location() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::new(&context, "foo", 1, 1))
+            .add_results(&[Type::index(&context)])
+            .build();
+        let other = OperationBuilder::new("foo", Location::new(&context, "bar", 2, 2))
+            .add_results(&[Type::index(&context)])
+            .build();
+
+        assert_ne!(operation, other);
+        assert!(operation.structurally_equal_ignoring_location(&other));
+        assert!(!operation.structurally_equal_ignoring_location(
+            &OperationBuilder::new("bar", Location::new(&context, "foo", 1, 1)).build()
+        ));
+    }
+
+    #[test]
+    fn to_string_with_same_flags() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let flags = OperationPrintingFlags::new().print_generic_operation_form();
+
+        assert_eq!(
+            OperationBuilder::new("foo", location)
+                .build()
+                .to_string_with_flags(&flags),
+            Ok("\"foo\"() : () -> ()\n".into())
+        );
+        assert_eq!(
+            OperationBuilder::new("bar", location)
+                .build()
+                .to_string_with_flags(&flags),
+            Ok("\"bar\"() : () -> ()\n".into())
+        );
+    }
+
+    #[test]
+    fn emit_error() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let operation =
+            OperationBuilder::new("foo", Location::new(&context, "foo.mlir", 1, 2)).build();
+
+        let ((), diagnostics) = context.with_captured_diagnostics(|| operation.emit_error("bar"));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[0].message(), "bar");
+        assert_eq!(diagnostics[0].location(), "loc(\"foo.mlir\":1:2)");
+    }
+
+    #[test]
+    fn verify_all() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+
+        assert!(module.as_operation().verify_all());
+
+        module
+            .body()
+            .append_operation(OperationBuilder::new("func.return", location).build());
+
+        assert!(!module.as_operation().verify_all());
+    }
+
+    #[test]
+    fn content_hash() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let lhs = Value::from(block.argument(0).unwrap());
+        let rhs = Value::from(block.argument(1).unwrap());
+
+        assert_eq!(
+            arith::addi(lhs, rhs, location).content_hash(),
+            arith::addi(rhs, lhs, location).content_hash()
+        );
+        assert_ne!(
+            arith::addi(lhs, rhs, location).content_hash(),
+            arith::subi(lhs, rhs, location).content_hash()
+        );
+    }
+
+    #[test]
+    fn to_string_local() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                    %0 = arith.addi %arg0, %arg0 : i32
+                    return %0 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let function = module.body().first_operation().unwrap();
+        let region = function.region(0).unwrap();
+        let block = region.first_block().unwrap();
+        let string = block.first_operation().unwrap().to_string_local().unwrap();
+
+        assert!(string.contains("arith.addi"));
+        assert!(!string.contains("func.func"));
+    }
+
+    #[test]
+    fn fused_location() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::fused(
+            &context,
+            &[
+                Location::new(&context, "foo", 1, 2),
+                Location::new(&context, "bar", 3, 4),
+            ],
+            Attribute::unit(&context),
+        );
+
+        let operation = OperationBuilder::new("foo", location).build();
+
+        assert_eq!(operation.location(), location);
+        assert_eq!(
+            operation.location().to_string(),
+            "loc(fused<unit>[\"foo\":1:2, \"bar\":3:4])"
+        );
+    }
+
+    #[test]
+    fn verify_and_report() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+
+        let report = arith::constant(
+            &context,
+            Attribute::parse(&context, "42 : index").unwrap(),
+            location,
+        )
+        .verify_and_report();
+
+        assert!(report.is_valid());
+        assert!(report.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn verify_and_report_invalid() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let block = Block::new(&[(Type::index(&context), location)]);
+        let argument = block.argument(0).unwrap().into();
+
+        let report = OperationBuilder::new("arith.addi", location)
+            .add_operands(&[argument])
+            .add_results(&[Type::index(&context)])
+            .build()
+            .verify_and_report();
+
+        assert!(!report.is_valid());
+        assert!(!report.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn function() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                    return %arg0 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let function = module.body().first_operation().unwrap();
+        let integer_type = Type::parse(&context, "i32").unwrap();
+
+        assert_eq!(
+            function.function_type().map(Type::from),
+            Some(FunctionType::new(&context, &[integer_type], &[integer_type]).into())
+        );
+        assert_eq!(function.function_name(), Some("foo"));
+        assert_eq!(function.function_body(), Some(function.region(0).unwrap()));
+    }
+
+    #[test]
+    fn function_of_non_function() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context)).build();
+
+        assert!(operation.function_type().is_none());
+        assert_eq!(operation.function_name(), None);
+        assert_eq!(operation.function_body(), None);
+    }
+
+    #[test]
+    fn to_string_wrapped() {
+        let context = create_test_context();
+        let module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32, %arg1 : i32, %arg2 : i32) -> i32 {
+                    %0 = arith.addi %arg0, %arg1 : i32
+                    %1 = arith.addi %0, %arg2 : i32
+                    return %1 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let string = module.as_operation().to_string();
+        let wrapped = module.as_operation().to_string_wrapped(40);
+
+        assert!(string.lines().any(|line| line.len() > 40));
+        assert!(wrapped.lines().all(|line| line.len() <= 40));
+        assert_eq!(
+            Module::parse(&context, &wrapped)
+                .unwrap()
+                .as_operation()
+                .to_string(),
+            string
+        );
+    }
+
+    #[test]
+    fn set_operand() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let [first, second] = [0, 1].map(|index| Value::from(block.argument(index).unwrap()));
+
+        let mut operation = OperationBuilder::new("foo", location)
+            .add_operands(&[first])
+            .build();
+
+        assert_eq!(operation.set_operand(0, second), Ok(()));
+        assert_eq!(operation.operand(0), Ok(second));
+        assert!(operation.set_operand(1, second).is_err());
+    }
+
+    #[test]
+    fn remap_operands() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location), (r#type, location)]);
+        let [first, second, third] =
+            [0, 1, 2].map(|index| Value::from(block.argument(index).unwrap()));
+
+        let mut operation = OperationBuilder::new("foo", location)
+            .add_operands(&[first, second])
+            .build();
+
+        operation.remap_operands(&|value| (value == first).then_some(third));
+
+        assert_eq!(operation.operand(0), Ok(third));
+        assert_eq!(operation.operand(1), Ok(second));
+    }
+
+    #[test]
+    fn predicates_of_return() {
+        let context = create_test_context();
+        let block = Block::new(&[]);
+        let operation = block.append_operation(func::r#return(&[], Location::unknown(&context)));
+
+        assert!(operation.has_no_operands());
+        assert!(operation.has_no_results());
+        assert!(operation.has_no_regions());
+        assert!(operation.is_terminator());
+    }
+
+    #[test]
+    fn predicates_of_constant() {
+        let context = create_test_context();
+        let block = Block::new(&[]);
+        let operation = block.append_operation(arith::constant(
+            &context,
+            Attribute::parse(&context, "42 : index").unwrap(),
+            Location::unknown(&context),
+        ));
+
+        assert!(operation.has_no_operands());
+        assert!(!operation.has_no_results());
+        assert!(operation.has_no_regions());
+        assert!(!operation.is_terminator());
+    }
+
+    #[test]
+    fn clone_with_mapping() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let block = Block::new(&[
+            (Type::index(&context), location),
+            (Type::index(&context), location),
+        ]);
+        let from = Value::from(block.argument(0).unwrap());
+        let to = Value::from(block.argument(1).unwrap());
+        let operation = OperationBuilder::new("foo", location)
+            .add_operands(&[from])
+            .build();
+        let mut mapping = IrMapping::new();
+
+        mapping.map_value(from, to);
+
+        assert_eq!(operation.clone_with_mapping(&mapping).operand(0), Ok(to));
+        assert_eq!(operation.operand(0), Ok(from));
+    }
+
+    #[test]
+    fn clone_with_mapping_addi() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let lhs = Value::from(block.argument(0).unwrap());
+        let rhs = Value::from(block.argument(1).unwrap());
+        let operation = block.append_operation(arith::addi(lhs, rhs, location));
+        let one = block.append_operation(arith::constant(
+            &context,
+            Attribute::parse(&context, "1 : index").unwrap(),
+            location,
+        ));
+        let two = block.append_operation(arith::constant(
+            &context,
+            Attribute::parse(&context, "2 : index").unwrap(),
+            location,
+        ));
+        let mut mapping = IrMapping::new();
+
+        mapping.map_value(lhs, one.result(0).unwrap().into());
+        mapping.map_value(rhs, two.result(0).unwrap().into());
+
+        let clone = operation.clone_with_mapping(&mapping);
+
+        assert_eq!(clone.operand(0), Ok(one.result(0).unwrap().into()));
+        assert_eq!(clone.operand(1), Ok(two.result(0).unwrap().into()));
+        assert_ne!(clone.operand(0), operation.operand(0));
+        assert_ne!(clone.operand(1), operation.operand(1));
+    }
+
+    #[test]
+    fn single_result() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_results(&[Type::index(&context)])
+            .build();
+
+        assert_eq!(
+            Value::from(operation.single_result().unwrap()),
+            operation.result(0).unwrap().into()
+        );
+    }
+
+    #[test]
+    fn single_result_without_results() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context)).build();
+
+        assert_eq!(
+            operation.single_result().unwrap_err(),
+            Error::SingleResultExpected {
+                count: 0,
+                operation: "\"foo\"() : () -> ()\n".into()
+            }
+        );
+    }
 }
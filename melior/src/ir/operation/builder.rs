@@ -1,20 +1,33 @@
-use super::Operation;
+use super::{Operation, OperationName};
 use crate::{
     context::Context,
-    ir::{Attribute, AttributeLike, Block, Identifier, Location, Region, Type, Value},
+    ir::{
+        Attribute, AttributeLike, Block, Identifier, Location, Region, Type, TypeLike, Value,
+        ValueLike,
+    },
     string_ref::StringRef,
+    Error,
 };
 use mlir_sys::{
-    mlirNamedAttributeGet, mlirOperationCreate, mlirOperationStateAddAttributes,
+    mlirDictionaryAttrGet, mlirIdentifierGet, mlirIdentifierStr,
+    mlirInferTypeOpInterfaceInferReturnTypes, mlirInferTypeOpInterfaceTypeID,
+    mlirLocationGetContext, mlirLogicalResultIsSuccess, mlirNamedAttributeGet, mlirOperationCreate,
+    mlirOperationImplementsInterfaceStatic, mlirOperationStateAddAttributes,
     mlirOperationStateAddOperands, mlirOperationStateAddOwnedRegions, mlirOperationStateAddResults,
     mlirOperationStateAddSuccessors, mlirOperationStateEnableResultTypeInference,
-    mlirOperationStateGet, MlirOperationState,
+    mlirOperationStateGet, MlirBlock, MlirNamedAttribute, MlirOperationState, MlirType, MlirValue,
 };
-use std::marker::PhantomData;
+use std::{ffi::c_void, marker::PhantomData, slice};
 
 /// An operation builder.
+// Results, operands, successors, and attributes are buffered until an
+// operation is built so that they are passed to an operation state at once.
 pub struct OperationBuilder<'c> {
     raw: MlirOperationState,
+    results: Vec<MlirType>,
+    operands: Vec<MlirValue>,
+    successors: Vec<MlirBlock>,
+    attributes: Vec<MlirNamedAttribute>,
     _context: PhantomData<&'c Context>,
 }
 
@@ -25,32 +38,65 @@ impl<'c> OperationBuilder<'c> {
             raw: unsafe {
                 mlirOperationStateGet(StringRef::from(name).to_raw(), location.to_raw())
             },
+            results: vec![],
+            operands: vec![],
+            successors: vec![],
+            attributes: vec![],
             _context: Default::default(),
         }
     }
 
+    /// Creates an operation builder with an operation name interned already.
+    pub fn with_name(name: OperationName<'c>, location: Location<'c>) -> Self {
+        Self {
+            raw: unsafe {
+                mlirOperationStateGet(
+                    mlirIdentifierStr(name.as_identifier().to_raw()),
+                    location.to_raw(),
+                )
+            },
+            results: vec![],
+            operands: vec![],
+            successors: vec![],
+            attributes: vec![],
+            _context: Default::default(),
+        }
+    }
+
+    /// Reserves capacity for at least a given number of additional results.
+    pub fn reserve_results(mut self, additional: usize) -> Self {
+        self.results.reserve(additional);
+
+        self
+    }
+
+    /// Reserves capacity for at least a given number of additional operands.
+    pub fn reserve_operands(mut self, additional: usize) -> Self {
+        self.operands.reserve(additional);
+
+        self
+    }
+
+    /// Reserves capacity for at least a given number of additional
+    /// attributes.
+    pub fn reserve_attributes(mut self, additional: usize) -> Self {
+        self.attributes.reserve(additional);
+
+        self
+    }
+
     /// Adds results.
     pub fn add_results(mut self, results: &[Type<'c>]) -> Self {
-        unsafe {
-            mlirOperationStateAddResults(
-                &mut self.raw,
-                results.len() as isize,
-                results as *const _ as *const _,
-            )
-        }
+        self.results
+            .extend(results.iter().map(|r#type| r#type.to_raw()));
 
         self
     }
 
     /// Adds operands.
     pub fn add_operands(mut self, operands: &[Value<'c, '_>]) -> Self {
-        unsafe {
-            mlirOperationStateAddOperands(
-                &mut self.raw,
-                operands.len() as isize,
-                operands as *const _ as *const _,
-            )
-        }
+        self.operands
+            .extend(operands.iter().map(|operand| operand.to_raw()));
 
         self
     }
@@ -68,22 +114,20 @@ impl<'c> OperationBuilder<'c> {
         self
     }
 
+    /// Adds a region.
+    pub fn add_region(mut self, region: Region<'c>) -> Self {
+        unsafe { mlirOperationStateAddOwnedRegions(&mut self.raw, 1, &region.into_raw()) }
+
+        self
+    }
+
     /// Adds successor blocks.
     // TODO Fix this to ensure blocks are alive while they are referenced by the
     // operation.
     // TODO Should we accept `BlockRef`?
     pub fn add_successors(mut self, successors: &[&Block<'c>]) -> Self {
-        unsafe {
-            mlirOperationStateAddSuccessors(
-                &mut self.raw,
-                successors.len() as isize,
-                successors
-                    .iter()
-                    .map(|block| block.to_raw())
-                    .collect::<Vec<_>>()
-                    .as_ptr() as *const _,
-            )
-        }
+        self.successors
+            .extend(successors.iter().map(|block| block.to_raw()));
 
         self
     }
@@ -91,19 +135,25 @@ impl<'c> OperationBuilder<'c> {
     /// Adds attributes.
     // TODO Should we accept `NamedAttribute`?
     pub fn add_attributes(mut self, attributes: &[(Identifier<'c>, Attribute<'c>)]) -> Self {
-        unsafe {
-            mlirOperationStateAddAttributes(
-                &mut self.raw,
-                attributes.len() as isize,
-                attributes
-                    .iter()
-                    .map(|(identifier, attribute)| {
-                        mlirNamedAttributeGet(identifier.to_raw(), attribute.to_raw())
-                    })
-                    .collect::<Vec<_>>()
-                    .as_ptr() as *const _,
+        self.attributes
+            .extend(attributes.iter().map(|(identifier, attribute)| unsafe {
+                mlirNamedAttributeGet(identifier.to_raw(), attribute.to_raw())
+            }));
+
+        self
+    }
+
+    /// Adds an attribute with a name.
+    pub fn add_named_attribute(mut self, name: &str, attribute: impl Into<Attribute<'c>>) -> Self {
+        self.attributes.push(unsafe {
+            mlirNamedAttributeGet(
+                mlirIdentifierGet(
+                    mlirLocationGetContext(self.raw.location),
+                    StringRef::from(name).to_raw(),
+                ),
+                attribute.into().to_raw(),
             )
-        }
+        });
 
         self
     }
@@ -115,9 +165,154 @@ impl<'c> OperationBuilder<'c> {
         self
     }
 
+    /// Mutates a raw operation state directly.
+    ///
+    /// This is an escape hatch for fields not modeled by the builder yet.
+    /// Results, operands, successors, and attributes added by the builder are
+    /// buffered and not in the state until an operation is built. Fields set
+    /// on the state are not preserved when the builder is cloned.
+    ///
+    /// # Safety
+    ///
+    /// Any objects added to the state must be valid and live in the same
+    /// context until an operation is built. Fields of the state must not be
+    /// overwritten in a way that leaks or double-frees objects it owns, such
+    /// as regions.
+    pub unsafe fn with_raw(mut self, function: impl FnOnce(&mut MlirOperationState)) -> Self {
+        function(&mut self.raw);
+
+        self
+    }
+
     /// Builds an operation.
-    pub fn build(mut self) -> Operation<'c> {
-        unsafe { Operation::from_raw(mlirOperationCreate(&mut self.raw)) }
+    ///
+    /// # Panics
+    ///
+    /// Panics if result type inference is enabled but fails. Use
+    /// [`try_build`](Self::try_build) to handle the failure instead.
+    pub fn build(self) -> Operation<'c> {
+        self.try_build().expect("valid operation")
+    }
+
+    /// Builds an operation, failing early if result type inference is enabled
+    /// but fails (e.g. for operations not implementing
+    /// `InferTypeOpInterface`).
+    pub fn try_build(mut self) -> Result<Operation<'c>, Error> {
+        unsafe {
+            mlirOperationStateAddResults(
+                &mut self.raw,
+                self.results.len() as isize,
+                self.results.as_ptr(),
+            );
+            mlirOperationStateAddOperands(
+                &mut self.raw,
+                self.operands.len() as isize,
+                self.operands.as_ptr(),
+            );
+            mlirOperationStateAddSuccessors(
+                &mut self.raw,
+                self.successors.len() as isize,
+                self.successors.as_ptr(),
+            );
+            mlirOperationStateAddAttributes(
+                &mut self.raw,
+                self.attributes.len() as isize,
+                self.attributes.as_ptr(),
+            );
+
+            Operation::from_option_raw(mlirOperationCreate(&mut self.raw))
+                .ok_or_else(|| Error::ResultTypeInference(self.name()))
+        }
+    }
+
+    /// Infers result types without building an operation.
+    ///
+    /// The types are inferred by `InferTypeOpInterface` from operands,
+    /// attributes, and regions added so far. Results added explicitly are
+    /// ignored.
+    pub fn infer_result_types(&self) -> Result<Vec<Type<'c>>, Error> {
+        unsafe extern "C" fn callback(count: isize, types: *mut MlirType, data: *mut c_void) {
+            let results = &mut *(data as *mut Vec<MlirType>);
+
+            results.extend_from_slice(slice::from_raw_parts(types, count as usize));
+        }
+
+        unsafe {
+            let context = mlirLocationGetContext(self.raw.location);
+
+            if !mlirOperationImplementsInterfaceStatic(
+                self.raw.name,
+                context,
+                mlirInferTypeOpInterfaceTypeID(),
+            ) {
+                return Err(Error::ResultTypeInference(self.name()));
+            }
+
+            let mut types = Vec::<MlirType>::new();
+
+            if mlirLogicalResultIsSuccess(mlirInferTypeOpInterfaceInferReturnTypes(
+                self.raw.name,
+                context,
+                self.raw.location,
+                self.operands.len() as isize,
+                self.operands.as_ptr() as *mut _,
+                mlirDictionaryAttrGet(
+                    context,
+                    self.attributes.len() as isize,
+                    self.attributes.as_ptr(),
+                ),
+                self.raw.nRegions,
+                self.raw.regions,
+                Some(callback),
+                &mut types as *mut _ as *mut c_void,
+            )) {
+                Ok(types
+                    .into_iter()
+                    .map(|r#type| Type::from_raw(r#type))
+                    .collect())
+            } else {
+                Err(Error::ResultTypeInference(self.name()))
+            }
+        }
+    }
+
+    fn name(&self) -> String {
+        unsafe { StringRef::from_raw(self.raw.name) }
+            .as_str()
+            .unwrap_or_default()
+            .into()
+    }
+
+    /// Builds an operation without verifying it.
+    ///
+    /// This is equivalent to [`build`](Self::build), which never verifies
+    /// operations, but makes the intent explicit at call sites.
+    pub fn build_unverified(self) -> Operation<'c> {
+        self.build()
+    }
+}
+
+impl<'c> Clone for OperationBuilder<'c> {
+    /// Clones an operation builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any regions are added to a builder because they are owned by
+    /// it and cannot be shared.
+    fn clone(&self) -> Self {
+        assert_eq!(self.raw.nRegions, 0, "regions cannot be cloned");
+
+        let mut raw = unsafe { mlirOperationStateGet(self.raw.name, self.raw.location) };
+        raw.enableResultTypeInference = self.raw.enableResultTypeInference;
+
+        Self {
+            raw,
+            results: self.results.clone(),
+            operands: self.operands.clone(),
+            successors: self.successors.clone(),
+            attributes: self.attributes.clone(),
+            _context: Default::default(),
+        }
     }
 }
 
@@ -125,7 +320,7 @@ impl<'c> OperationBuilder<'c> {
 mod tests {
     use super::*;
     use crate::{
-        ir::{Block, ValueLike},
+        ir::{attribute::StringAttribute, Block, ValueLike},
         test::create_test_context,
     };
 
@@ -137,6 +332,44 @@ mod tests {
         OperationBuilder::new("foo", Location::unknown(&context)).build();
     }
 
+    #[test]
+    fn with_name() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let name = OperationName::new(&context, "foo.bar");
+
+        for _ in 0..100 {
+            let operation = OperationBuilder::with_name(name, location).build();
+
+            assert_eq!(operation.name(), Identifier::new(&context, "foo.bar"));
+        }
+    }
+
+    #[test]
+    fn with_raw() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let attribute = unsafe {
+            mlirNamedAttributeGet(
+                Identifier::new(&context, "foo").to_raw(),
+                StringAttribute::new(&context, "bar").to_raw(),
+            )
+        };
+        let operation = unsafe {
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .with_raw(|state| mlirOperationStateAddAttributes(state, 1, &attribute))
+        }
+        .build();
+
+        assert_eq!(
+            operation.attribute("foo"),
+            Ok(StringAttribute::new(&context, "bar").into())
+        );
+    }
+
     #[test]
     fn add_operands() {
         let context = create_test_context();
@@ -172,6 +405,18 @@ mod tests {
             .build();
     }
 
+    #[test]
+    fn add_region() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_region(Region::new())
+            .build();
+
+        assert_eq!(operation.region_count(), 1);
+    }
+
     #[test]
     fn add_successors() {
         let context = create_test_context();
@@ -195,6 +440,103 @@ mod tests {
             .build();
     }
 
+    #[test]
+    fn add_named_attribute() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let operation = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_named_attribute("bar", Attribute::unit(&context))
+            .add_named_attribute("baz", StringAttribute::new(&context, "qux"))
+            .build();
+
+        assert_eq!(
+            operation.attributes().collect::<Vec<_>>(),
+            vec![
+                (Identifier::new(&context, "bar"), Attribute::unit(&context)),
+                (
+                    Identifier::new(&context, "baz"),
+                    StringAttribute::new(&context, "qux").into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_unverified() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        assert_eq!(
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .build_unverified()
+                .to_string(),
+            OperationBuilder::new("foo", Location::unknown(&context))
+                .build()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn reserve_operands() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[(Type::index(&context), location)]);
+        let argument = block.argument(0).unwrap().into();
+
+        let mut builder = OperationBuilder::new("foo", location)
+            .reserve_results(1)
+            .reserve_attributes(1)
+            .reserve_operands(10000);
+
+        for _ in 0..10000 {
+            builder = builder.add_operands(&[argument]);
+        }
+
+        let operation = builder
+            .add_results(&[Type::index(&context)])
+            .add_attributes(&[(Identifier::new(&context, "foo"), Attribute::unit(&context))])
+            .build();
+
+        assert_eq!(operation.operand_count(), 10000);
+        assert!(operation.operands().all(|operand| operand == argument));
+        assert_eq!(operation.result_count(), 1);
+        assert!(operation.has_attribute("foo"));
+    }
+
+    #[test]
+    fn clone() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location), (r#type, location)]);
+        let argument = block.argument(0).unwrap().into();
+        let other_argument = block.argument(1).unwrap().into();
+
+        let builder = OperationBuilder::new("foo", location).add_operands(&[argument]);
+        let operation = builder.clone().add_operands(&[other_argument]).build();
+        let other_operation = builder.build();
+
+        assert_eq!(operation.operand_count(), 2);
+        assert_eq!(other_operation.operand_count(), 1);
+        assert_eq!(other_operation.operand(0).unwrap(), argument);
+    }
+
+    #[test]
+    #[should_panic(expected = "regions cannot be cloned")]
+    fn clone_with_regions() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let _ = OperationBuilder::new("foo", Location::unknown(&context))
+            .add_regions(vec![Region::new()])
+            .clone();
+    }
+
     #[test]
     fn enable_result_type_inference() {
         let context = create_test_context();
@@ -216,4 +558,54 @@ mod tests {
             r#type,
         );
     }
+
+    #[test]
+    fn infer_result_types() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument = block.argument(0).unwrap().into();
+
+        assert_eq!(
+            OperationBuilder::new("arith.addi", location)
+                .add_operands(&[argument, argument])
+                .infer_result_types(),
+            Ok(vec![r#type])
+        );
+    }
+
+    #[test]
+    fn infer_result_types_without_interface() {
+        let context = create_test_context();
+
+        assert_eq!(
+            OperationBuilder::new("func.return", Location::unknown(&context)).infer_result_types(),
+            Err(Error::ResultTypeInference("func.return".into()))
+        );
+    }
+
+    #[test]
+    fn try_build_with_failed_result_type_inference() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        assert_eq!(
+            OperationBuilder::new("func.return", Location::unknown(&context))
+                .enable_result_type_inference()
+                .try_build()
+                .unwrap_err(),
+            Error::ResultTypeInference("func.return".into())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "valid operation")]
+    fn build_with_failed_result_type_inference() {
+        let context = create_test_context();
+
+        OperationBuilder::new("func.return", Location::unknown(&context))
+            .enable_result_type_inference()
+            .build();
+    }
 }
@@ -0,0 +1,39 @@
+use crate::{context::Context, ir::Identifier};
+
+/// An operation name.
+///
+/// It is interned in a context once so that operations can be built from it
+/// repeatedly without converting strings each time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OperationName<'c> {
+    identifier: Identifier<'c>,
+}
+
+impl<'c> OperationName<'c> {
+    /// Creates an operation name.
+    pub fn new(context: &'c Context, name: &str) -> Self {
+        Self {
+            identifier: Identifier::new(context, name),
+        }
+    }
+
+    /// Converts an operation name into an identifier.
+    pub fn as_identifier(&self) -> Identifier<'c> {
+        self.identifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            OperationName::new(&context, "foo.bar").as_identifier(),
+            Identifier::new(&context, "foo.bar")
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use super::{Operation, OperationRef};
+use crate::ir::{Block, BlockRef, Region, TypeLike, ValueLike};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// A structured representation of an operation.
+///
+/// It is a tree of strings for external tools, and is serializable with the
+/// `serde` feature.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OperationNode {
+    name: String,
+    attributes: Vec<(String, String)>,
+    operand_types: Vec<String>,
+    result_types: Vec<String>,
+    regions: Vec<RegionNode>,
+}
+
+impl OperationNode {
+    pub(crate) fn new(operation: &Operation) -> Self {
+        Self {
+            name: operation
+                .name()
+                .as_string_ref()
+                .as_str()
+                .unwrap_or_default()
+                .into(),
+            attributes: operation
+                .attributes()
+                .map(|(name, attribute)| {
+                    (
+                        name.as_string_ref().as_str().unwrap_or_default().into(),
+                        attribute.to_string(),
+                    )
+                })
+                .collect(),
+            operand_types: operation
+                .operands()
+                .map(|operand| operand.r#type().to_string())
+                .collect(),
+            result_types: operation
+                .results()
+                .map(|result| result.r#type().to_string())
+                .collect(),
+            regions: operation
+                .regions()
+                .map(|region| RegionNode::new(&region))
+                .collect(),
+        }
+    }
+
+    /// Gets a name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets attributes as pairs of names and printed attributes.
+    pub fn attributes(&self) -> &[(String, String)] {
+        &self.attributes
+    }
+
+    /// Gets printed operand types.
+    pub fn operand_types(&self) -> &[String] {
+        &self.operand_types
+    }
+
+    /// Gets printed result types.
+    pub fn result_types(&self) -> &[String] {
+        &self.result_types
+    }
+
+    /// Gets regions.
+    pub fn regions(&self) -> &[RegionNode] {
+        &self.regions
+    }
+}
+
+/// A structured representation of a region.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RegionNode {
+    blocks: Vec<BlockNode>,
+}
+
+impl RegionNode {
+    fn new(region: &Region) -> Self {
+        let mut blocks = vec![];
+        let mut block = region.first_block().map(|block| block.to_raw());
+
+        while let Some(raw) = block {
+            let r#ref = unsafe { BlockRef::from_raw(raw) };
+
+            blocks.push(BlockNode::new(&r#ref));
+            block = r#ref.next_in_region().map(|block| block.to_raw());
+        }
+
+        Self { blocks }
+    }
+
+    /// Gets blocks.
+    pub fn blocks(&self) -> &[BlockNode] {
+        &self.blocks
+    }
+}
+
+/// A structured representation of a block.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BlockNode {
+    argument_types: Vec<String>,
+    operations: Vec<OperationNode>,
+}
+
+impl BlockNode {
+    fn new(block: &Block) -> Self {
+        let mut operations = vec![];
+        let mut operation = block.first_operation().map(|operation| operation.to_raw());
+
+        while let Some(raw) = operation {
+            let r#ref = unsafe { OperationRef::from_raw(raw) };
+
+            operations.push(OperationNode::new(&r#ref));
+            operation = r#ref.next_in_block().map(|operation| operation.to_raw());
+        }
+
+        Self {
+            argument_types: block
+                .argument_types()
+                .iter()
+                .map(|r#type| r#type.to_string())
+                .collect(),
+            operations,
+        }
+    }
+
+    /// Gets printed argument types.
+    pub fn argument_types(&self) -> &[String] {
+        &self.argument_types
+    }
+
+    /// Gets operations.
+    pub fn operations(&self) -> &[OperationNode] {
+        &self.operations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ir::{
+            attribute::TypeAttribute, operation::OperationBuilder, Block, Location, Region, Type,
+        },
+        test::create_test_context,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn to_structured() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let r#type = Type::index(&context);
+        let block = Block::new(&[(r#type, location)]);
+        let argument = block.argument(0).unwrap().into();
+        block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[argument])
+                .add_results(&[r#type])
+                .build(),
+        );
+        let region = Region::new();
+        region.append_block(block);
+
+        let node = OperationBuilder::new("foo", location)
+            .add_named_attribute("baz", TypeAttribute::new(r#type))
+            .add_regions(vec![region])
+            .build()
+            .to_structured();
+
+        assert_eq!(node.name(), "foo");
+        assert_eq!(
+            node.attributes(),
+            &[("baz".to_string(), "index".to_string())]
+        );
+        assert_eq!(node.regions()[0].blocks()[0].argument_types(), &["index"]);
+
+        let nested = &node.regions()[0].blocks()[0].operations()[0];
+
+        assert_eq!(nested.name(), "bar");
+        assert_eq!(nested.operand_types(), &["index"]);
+        assert_eq!(nested.result_types(), &["index"]);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&node).unwrap();
+
+            assert!(json.contains(r#""name":"foo""#));
+            assert!(json.contains(r#""operations":[{"name":"bar""#));
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use super::Operation;
+use crate::ir::OperationRef;
+use mlir_sys::{
+    mlirOpOperandGetNextUse, mlirOpOperandGetOperandNumber, mlirOpOperandGetOwner,
+    mlirOpOperandIsNull, MlirOpOperand,
+};
+use std::marker::PhantomData;
+
+/// An operation operand, which is a use of a value by an operation.
+#[derive(Clone, Copy, Debug)]
+pub struct OperationOperand<'c, 'a> {
+    raw: MlirOpOperand,
+    _reference: PhantomData<&'a Operation<'c>>,
+}
+
+impl<'c, 'a> OperationOperand<'c, 'a> {
+    /// Gets an owner operation.
+    pub fn owner(&self) -> OperationRef<'c, 'a> {
+        unsafe { OperationRef::from_raw(mlirOpOperandGetOwner(self.raw)) }
+    }
+
+    /// Gets an operand number in an owner operation.
+    pub fn operand_number(&self) -> usize {
+        unsafe { mlirOpOperandGetOperandNumber(self.raw) as usize }
+    }
+
+    /// Gets a next use of the same value.
+    pub fn next_use(&self) -> Option<Self> {
+        unsafe { Self::from_option_raw(mlirOpOperandGetNextUse(self.raw)) }
+    }
+
+    /// Creates an operation operand from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirOpOperand) -> Self {
+        Self {
+            raw,
+            _reference: Default::default(),
+        }
+    }
+
+    /// Creates an optional operation operand from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_option_raw(raw: MlirOpOperand) -> Option<Self> {
+        if mlirOpOperandIsNull(raw) {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+
+    /// Converts an operation operand into a raw object.
+    pub const fn to_raw(self) -> MlirOpOperand {
+        self.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ir::{operation::OperationBuilder, Block, Location, Type, ValueLike},
+        test::create_test_context,
+    };
+
+    #[test]
+    fn owner_and_operand_number() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[(Type::index(&context), location)]);
+        let argument = block.argument(0).unwrap();
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument.into(), argument.into()])
+                .build(),
+        );
+
+        let first = argument.first_use().unwrap();
+        let second = first.next_use().unwrap();
+
+        assert_eq!(first.owner(), operation);
+        assert_eq!(
+            [first.operand_number(), second.operand_number()]
+                .iter()
+                .sum::<usize>(),
+            1
+        );
+        assert!(second.next_use().is_none());
+    }
+}
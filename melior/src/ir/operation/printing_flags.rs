@@ -5,6 +5,9 @@ use mlir_sys::{
 };
 
 /// Operation printing flags.
+///
+/// Printing flags own their raw object, which is destroyed when they are
+/// dropped. They can be borrowed to print any number of operations.
 #[derive(Debug)]
 pub struct OperationPrintingFlags(MlirOpPrintingFlags);
 
@@ -3,9 +3,15 @@ use crate::{
     Error,
 };
 use mlir_sys::{mlirOpResultGetOwner, mlirOpResultGetResultNumber, MlirValue};
-use std::fmt::{self, Display, Formatter};
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::Deref,
+};
 
 /// An operation result.
+///
+/// It dereferences to [`Value`] so that all value methods are available on
+/// results, such as [`ValueLike::r#type`] to get a type.
 #[derive(Clone, Copy, Debug)]
 pub struct OperationResult<'c, 'a> {
     value: Value<'c, 'a>,
@@ -40,6 +46,14 @@ impl<'c, 'a> ValueLike<'c> for OperationResult<'c, 'a> {
     }
 }
 
+impl<'c, 'a> Deref for OperationResult<'c, 'a> {
+    type Target = Value<'c, 'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
 impl<'c, 'a> Display for OperationResult<'c, 'a> {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         Value::from(*self).fmt(formatter)
@@ -61,7 +75,11 @@ impl<'c, 'a> TryFrom<Value<'c, 'a>> for OperationResult<'c, 'a> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        ir::{operation::OperationBuilder, Block, Location, Type},
+        dialect::arith,
+        ir::{
+            attribute::IntegerAttribute, operation::OperationBuilder, Block, Location, Type, Value,
+            ValueLike,
+        },
         test::create_test_context,
     };
 
@@ -86,4 +104,24 @@ mod tests {
 
         assert_eq!(&*block.argument(0).unwrap().owner(), &block);
     }
+
+    #[test]
+    fn r#type() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let r#type = Type::integer(&context, 32);
+        let block = Block::new(&[]);
+        let constant = block.append_operation(arith::constant(
+            &context,
+            IntegerAttribute::new(42, r#type).unwrap().into(),
+            location,
+        ));
+        let value = constant.result(0).unwrap().into();
+        let operation = block.append_operation(arith::addi(value, value, location));
+
+        let result = operation.result(0).unwrap();
+
+        assert_eq!(result.r#type(), r#type);
+        assert_eq!(*result, Value::from(result));
+    }
 }
@@ -0,0 +1,22 @@
+/// A report of operation verification.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationReport {
+    valid: bool,
+    diagnostics: Vec<String>,
+}
+
+impl VerificationReport {
+    pub(crate) fn new(valid: bool, diagnostics: Vec<String>) -> Self {
+        Self { valid, diagnostics }
+    }
+
+    /// Returns `true` if an operation is valid.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Gets messages of diagnostics emitted during verification.
+    pub fn diagnostics(&self) -> &[String] {
+        &self.diagnostics
+    }
+}
@@ -0,0 +1,10 @@
+/// A result of a callback during a walk over operations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkResult {
+    /// Continues a walk.
+    Advance,
+    /// Stops a walk.
+    Interrupt,
+    /// Continues a walk skipping regions of a current operation.
+    Skip,
+}
@@ -1,13 +1,20 @@
-use super::{Block, BlockRef};
+use super::{Block, BlockRef, OperationRef};
+use crate::{utility::region_blocks, StringRef};
 use mlir_sys::{
-    mlirRegionAppendOwnedBlock, mlirRegionCreate, mlirRegionDestroy, mlirRegionEqual,
-    mlirRegionGetFirstBlock, mlirRegionInsertOwnedBlockAfter, mlirRegionInsertOwnedBlockBefore,
+    mlirBlockCreate, mlirBlockDetach, mlirBlockGetArgument, mlirBlockGetFirstOperation,
+    mlirBlockGetNextInRegion, mlirBlockGetNumArguments, mlirBlockGetParentOperation,
+    mlirLocationUnknownGet, mlirOperationClone, mlirOperationCreate, mlirOperationDestroy,
+    mlirOperationGetContext, mlirOperationGetRegion, mlirOperationStateAddOwnedRegions,
+    mlirOperationStateGet, mlirRegionAppendOwnedBlock, mlirRegionCreate, mlirRegionDestroy,
+    mlirRegionEqual, mlirRegionGetFirstBlock, mlirRegionInsertOwnedBlockAfter,
+    mlirRegionInsertOwnedBlockBefore, mlirTypeGetContext, mlirValueGetType, MlirBlock, MlirContext,
     MlirRegion,
 };
 use std::{
     marker::PhantomData,
     mem::{forget, transmute},
     ops::Deref,
+    ptr::null,
 };
 
 /// A region.
@@ -39,6 +46,92 @@ impl<'c> Region<'c> {
         }
     }
 
+    /// Gets a parent operation.
+    ///
+    /// A parent operation is found through the first block. So it returns
+    /// `None` for empty regions even if they are attached to operations.
+    pub fn parent_operation(&self) -> Option<OperationRef<'c, '_>> {
+        let block = unsafe { mlirRegionGetFirstBlock(self.raw) };
+
+        if block.ptr.is_null() {
+            None
+        } else {
+            unsafe { OperationRef::from_option_raw(mlirBlockGetParentOperation(block)) }
+        }
+    }
+
+    /// Computes predecessors of blocks in a region.
+    ///
+    /// Predecessors are not stored in MLIR. They are computed from successors
+    /// of every block's terminator each time this function is called.
+    pub fn predecessors(&self) -> Vec<(BlockRef<'c, '_>, Vec<BlockRef<'c, '_>>)> {
+        let mut blocks = vec![];
+        let mut block = unsafe { mlirRegionGetFirstBlock(self.raw) };
+
+        while !block.ptr.is_null() {
+            blocks.push(unsafe { BlockRef::from_raw(block) });
+            block = unsafe { mlirBlockGetNextInRegion(block) };
+        }
+
+        blocks
+            .iter()
+            .map(|&block| {
+                (
+                    block,
+                    blocks
+                        .iter()
+                        .filter(|other| other.successors().any(|successor| successor == block))
+                        .copied()
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Clones blocks of a region into another region.
+    ///
+    /// Cloned blocks are appended to the destination region. Uses of values
+    /// and blocks defined in the source region are remapped to their clones
+    /// while values defined outside of it are used as they are.
+    pub fn clone_into(&self, destination: &Region<'c>) {
+        unsafe {
+            let blocks = region_blocks(self.raw);
+            let context = match blocks.iter().find_map(|&block| block_context(block)) {
+                Some(context) => context,
+                None => {
+                    // Blocks without any arguments or operations are empty.
+                    for _ in blocks {
+                        mlirRegionAppendOwnedBlock(
+                            destination.raw,
+                            mlirBlockCreate(0, null(), null()),
+                        );
+                    }
+
+                    return;
+                }
+            };
+
+            // Only operations can be cloned. So blocks are moved into a
+            // temporary operation while it is cloned.
+            let region = mlirRegionCreate();
+            let mut state = mlirOperationStateGet(
+                StringRef::from("builtin.module").to_raw(),
+                mlirLocationUnknownGet(context),
+            );
+            mlirOperationStateAddOwnedRegions(&mut state, 1, &region);
+            let operation = mlirOperationCreate(&mut state);
+            let region = mlirOperationGetRegion(operation, 0);
+
+            move_blocks(self.raw, region);
+            let clone = mlirOperationClone(operation);
+            move_blocks(region, self.raw);
+            move_blocks(mlirOperationGetRegion(clone, 0), destination.raw);
+
+            mlirOperationDestroy(clone);
+            mlirOperationDestroy(operation);
+        }
+    }
+
     /// Inserts a block after another block.
     pub fn insert_block_after(&self, one: BlockRef<'c, '_>, other: Block<'c>) -> BlockRef<'c, '_> {
         unsafe {
@@ -72,6 +165,11 @@ impl<'c> Region<'c> {
         }
     }
 
+    /// Converts a region into a raw object.
+    pub const fn to_raw(&self) -> MlirRegion {
+        self.raw
+    }
+
     /// Converts a region into a raw object.
     pub fn into_raw(self) -> mlir_sys::MlirRegion {
         let region = self.raw;
@@ -152,9 +250,40 @@ impl<'c, 'a> PartialEq for RegionRef<'c, 'a> {
 
 impl<'c, 'a> Eq for RegionRef<'c, 'a> {}
 
+unsafe fn block_context(block: MlirBlock) -> Option<MlirContext> {
+    let operation = mlirBlockGetFirstOperation(block);
+
+    if mlirBlockGetNumArguments(block) > 0 {
+        Some(mlirTypeGetContext(mlirValueGetType(mlirBlockGetArgument(
+            block, 0,
+        ))))
+    } else if !operation.ptr.is_null() {
+        Some(mlirOperationGetContext(operation))
+    } else {
+        None
+    }
+}
+
+unsafe fn move_blocks(source: MlirRegion, destination: MlirRegion) {
+    for block in region_blocks(source) {
+        mlirBlockDetach(block);
+        mlirRegionAppendOwnedBlock(destination, block);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        dialect::{cf, func},
+        ir::{
+            attribute::{StringAttribute, TypeAttribute},
+            operation::{OperationBuilder, OperationPrintingFlags},
+            r#type::{FunctionType, IntegerType},
+            Location, Type,
+        },
+        test::create_test_context,
+    };
 
     #[test]
     fn new() {
@@ -166,6 +295,49 @@ mod tests {
         assert!(Region::new().first_block().is_none());
     }
 
+    #[test]
+    fn first_block_of_non_empty_region() {
+        let region = Region::new();
+        let block = region.append_block(Block::new(&[]));
+
+        region.append_block(Block::new(&[]));
+
+        assert_eq!(region.first_block(), Some(block));
+    }
+
+    #[test]
+    fn parent_operation() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let region = Region::new();
+        region.append_block(Block::new(&[]));
+
+        let function = func::func(
+            &context,
+            StringAttribute::new(&context, "foo"),
+            TypeAttribute::new(FunctionType::new(&context, &[], &[]).into()),
+            region,
+            &[],
+            location,
+        );
+
+        assert_eq!(
+            function.region(0).unwrap().parent_operation().as_deref(),
+            Some(&function)
+        );
+    }
+
+    #[test]
+    fn parent_operation_none() {
+        let region = Region::new();
+
+        assert_eq!(region.parent_operation(), None);
+
+        region.append_block(Block::new(&[]));
+
+        assert_eq!(region.parent_operation(), None);
+    }
+
     #[test]
     fn append_block() {
         let region = Region::new();
@@ -207,4 +379,192 @@ mod tests {
     fn not_equal() {
         assert_ne!(Region::new(), Region::new());
     }
+
+    #[test]
+    fn predecessors() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let region = Region::new();
+
+        let entry = region.append_block(Block::new(&[(
+            IntegerType::new(&context, 1).into(),
+            location,
+        )]));
+        let then = region.append_block(Block::new(&[]));
+        let r#else = region.append_block(Block::new(&[]));
+        let exit = region.append_block(Block::new(&[]));
+
+        entry.append_operation(cf::cond_br(
+            &context,
+            entry.argument(0).unwrap().into(),
+            &then,
+            &r#else,
+            &[],
+            &[],
+            location,
+        ));
+        then.append_operation(cf::br(&exit, &[], location));
+        r#else.append_operation(cf::br(&exit, &[], location));
+
+        assert_eq!(entry.successors().collect::<Vec<_>>(), vec![then, r#else]);
+        assert_eq!(
+            region.predecessors(),
+            vec![
+                (entry, vec![]),
+                (then, vec![entry]),
+                (r#else, vec![entry]),
+                (exit, vec![then, r#else]),
+            ]
+        );
+    }
+
+    #[test]
+    fn clone_into() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let region = Region::new();
+
+        let block = region.append_block(Block::new(&[(index_type, location)]));
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[block.argument(0).unwrap().into()])
+                .add_results(&[index_type])
+                .build(),
+        );
+        block.append_operation(
+            OperationBuilder::new("bar", location)
+                .add_operands(&[operation.result(0).unwrap().into()])
+                .build(),
+        );
+
+        let clone = Region::new();
+        region.clone_into(&clone);
+
+        let block = clone.first_block().unwrap();
+        let operation = block.first_operation().unwrap();
+
+        assert_eq!(operation.operand(0), Ok(block.argument(0).unwrap().into()));
+        assert_eq!(
+            operation.next_in_block().unwrap().operand(0),
+            Ok(operation.result(0).unwrap().into())
+        );
+
+        let print = |region| {
+            OperationBuilder::new("baz", location)
+                .add_regions(vec![region])
+                .build()
+                .to_string()
+        };
+
+        assert_eq!(print(clone), print(region));
+    }
+
+    #[test]
+    fn clone_into_with_successors() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let index_type = Type::index(&context);
+        let region = Region::new();
+
+        let entry = region.append_block(Block::new(&[(index_type, location)]));
+        let exit = region.append_block(Block::new(&[(index_type, location)]));
+
+        entry.append_operation(cf::br(
+            &exit,
+            &[entry.argument(0).unwrap().into()],
+            location,
+        ));
+        exit.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[exit.argument(0).unwrap().into()])
+                .build(),
+        );
+
+        let clone = Region::new();
+        region.clone_into(&clone);
+
+        let entry = clone.first_block().unwrap();
+
+        assert_eq!(
+            entry.successors().collect::<Vec<_>>(),
+            vec![entry.next_in_region().unwrap()]
+        );
+
+        let print = |region| {
+            OperationBuilder::new("bar", location)
+                .add_regions(vec![region])
+                .build()
+                .to_string()
+        };
+
+        assert_eq!(print(clone), print(region));
+    }
+
+    #[test]
+    fn clone_into_with_argument_locations() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let region = Region::new();
+
+        region.append_block(Block::new(&[(
+            Type::index(&context),
+            Location::new(&context, "foo.mlir", 1, 2),
+        )]));
+
+        let clone = Region::new();
+        region.clone_into(&clone);
+
+        assert!(OperationBuilder::new("foo", Location::unknown(&context))
+            .add_region(clone)
+            .build()
+            .to_string_with_flags(&OperationPrintingFlags::new().enable_debug_info(true, false))
+            .unwrap()
+            .contains("%arg0: index loc(\"foo.mlir\":1:2)"));
+    }
+
+    #[test]
+    fn clone_into_with_successors_and_regions() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+        let location = Location::unknown(&context);
+        let region = Region::new();
+
+        let entry = region.append_block(Block::new(&[]));
+        let exit = region.append_block(Block::new(&[]));
+
+        entry.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_successors(&[&exit])
+                .add_regions(vec![{
+                    let region = Region::new();
+                    region.append_block(Block::new(&[]));
+                    region
+                }])
+                .build(),
+        );
+        exit.append_operation(OperationBuilder::new("bar", location).build());
+
+        let clone = Region::new();
+        region.clone_into(&clone);
+
+        let entry = clone.first_block().unwrap();
+
+        assert_eq!(
+            entry.successors().collect::<Vec<_>>(),
+            vec![entry.next_in_region().unwrap()]
+        );
+        assert_eq!(entry.first_operation().unwrap().region_count(), 1);
+        assert_eq!(
+            region
+                .first_block()
+                .unwrap()
+                .first_operation()
+                .unwrap()
+                .name(),
+            entry.first_operation().unwrap().name()
+        );
+    }
 }
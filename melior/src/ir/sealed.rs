@@ -0,0 +1,2 @@
+/// A trait that prevents IR traits from being implemented outside this crate.
+pub trait Sealed {}
@@ -16,8 +16,13 @@ pub use self::{
     ranked_tensor::RankedTensorType, shaped_type_like::ShapedTypeLike, tuple::TupleType,
     type_like::TypeLike,
 };
-use super::Location;
-use crate::{context::Context, string_ref::StringRef, utility::print_callback};
+use super::{sealed::Sealed, Location};
+use crate::{
+    context::Context,
+    string_ref::StringRef,
+    utility::{join_diagnostics, print_callback},
+    Error,
+};
 use mlir_sys::{
     mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet, mlirIndexTypeGet,
     mlirNoneTypeGet, mlirTypeEqual, mlirTypeParseGet, mlirTypePrint, mlirVectorTypeGet,
@@ -30,6 +35,19 @@ use std::{
 };
 
 /// A type.
+///
+/// Types are neither [`Send`] nor [`Sync`]. Multi-threading of
+/// contexts can be disabled from safe code, and the
+/// [`Context`](crate::Context) that types borrow is not [`Sync`] either.
+///
+/// ```compile_fail
+/// use melior::{ir::Type, Context};
+///
+/// let context = Box::leak(Box::new(Context::new()));
+/// let r#type = Type::index(context);
+///
+/// std::thread::spawn(move || r#type);
+/// ```
 // Types are always values but their internal storage is owned by contexts.
 #[derive(Clone, Copy)]
 pub struct Type<'c> {
@@ -39,13 +57,18 @@ pub struct Type<'c> {
 
 impl<'c> Type<'c> {
     /// Parses a type.
-    pub fn parse(context: &'c Context, source: &str) -> Option<Self> {
-        unsafe {
+    ///
+    /// Diagnostics emitted on failure are captured into an error instead of
+    /// being propagated to other diagnostic handlers.
+    pub fn parse(context: &'c Context, source: &str) -> Result<Self, Error> {
+        let (r#type, diagnostics) = context.with_captured_diagnostics(|| unsafe {
             Self::from_option_raw(mlirTypeParseGet(
                 context.to_raw(),
                 StringRef::from(source).to_raw(),
             ))
-        }
+        });
+
+        r#type.ok_or_else(|| Error::ParseType(join_diagnostics(&diagnostics)))
     }
 
     /// Creates a bfloat16 type.
@@ -68,6 +91,14 @@ impl<'c> Type<'c> {
         unsafe { Self::from_raw(mlirF64TypeGet(context.to_raw())) }
     }
 
+    /// Creates a signless integer type.
+    ///
+    /// Use [`IntegerType::signed`] or [`IntegerType::unsigned`] for integer
+    /// types with signedness semantics.
+    pub fn integer(context: &'c Context, bits: u32) -> Self {
+        IntegerType::new(context, bits).into()
+    }
+
     /// Creates an index type.
     pub fn index(context: &'c Context) -> Self {
         unsafe { Self::from_raw(mlirIndexTypeGet(context.to_raw())) }
@@ -131,6 +162,8 @@ impl<'c> Type<'c> {
     }
 }
 
+impl<'c> Sealed for Type<'c> {}
+
 impl<'c> TypeLike<'c> for Type<'c> {
     fn to_raw(&self) -> MlirType {
         self.raw
@@ -187,7 +220,17 @@ mod tests {
     #[test]
     fn new() {
         let context = create_test_context();
-        Type::parse(&context, "f32");
+        Type::parse(&context, "f32").unwrap();
+    }
+
+    #[test]
+    fn parse_invalid_vector() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::parse(&context, "vector<>"),
+            Err(Error::ParseType("expected non-function type".into()))
+        );
     }
 
     #[test]
@@ -200,6 +243,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn signless_integer() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::integer(&context, 32),
+            Type::parse(&context, "i32").unwrap()
+        );
+        assert_ne!(
+            Type::integer(&context, 32),
+            IntegerType::signed(&context, 32).into()
+        );
+        assert_ne!(
+            Type::integer(&context, 32),
+            IntegerType::unsigned(&context, 32).into()
+        );
+        assert_ne!(
+            Type::from(IntegerType::signed(&context, 32)),
+            IntegerType::unsigned(&context, 32).into()
+        );
+    }
+
     #[test]
     fn index() {
         let context = create_test_context();
@@ -241,7 +306,7 @@ mod tests {
                 &[42],
                 IntegerType::new(&context, 32).into()
             ),
-            Type::parse(&context, "vector<42xi32>")
+            Type::parse(&context, "vector<42xi32>").ok()
         );
     }
 
@@ -20,6 +20,8 @@ macro_rules! type_traits {
             }
         }
 
+        impl<'c> crate::ir::sealed::Sealed for $name<'c> {}
+
         impl<'c> crate::ir::r#type::TypeLike<'c> for $name<'c> {
             fn to_raw(&self) -> mlir_sys::MlirType {
                 self.r#type.to_raw()
@@ -1,9 +1,11 @@
 use crate::Error;
 
-use super::{Type, TypeLike};
+use super::{IntegerType, Type, TypeLike};
 use mlir_sys::{
-    mlirShapedTypeGetDimSize, mlirShapedTypeGetElementType, mlirShapedTypeGetRank,
-    mlirShapedTypeHasRank,
+    mlirAttributeGetNull, mlirMemRefTypeGet, mlirMemRefTypeGetLayout, mlirMemRefTypeGetMemorySpace,
+    mlirRankedTensorTypeGet, mlirRankedTensorTypeGetEncoding, mlirShapedTypeGetDimSize,
+    mlirShapedTypeGetElementType, mlirShapedTypeGetRank, mlirShapedTypeHasRank,
+    mlirShapedTypeHasStaticShape, mlirUnrankedMemrefGetMemorySpace, mlirVectorTypeGet,
 };
 
 /// Trait for shaped types.
@@ -35,13 +37,115 @@ pub trait ShapedTypeLike<'c>: TypeLike<'c> {
     fn has_rank(&self) -> bool {
         unsafe { mlirShapedTypeHasRank(self.to_raw()) }
     }
+
+    /// Checks if a type has a static shape.
+    fn has_static_shape(&self) -> bool {
+        unsafe { mlirShapedTypeHasStaticShape(self.to_raw()) }
+    }
+
+    /// Gets a number of elements.
+    ///
+    /// It returns `None` if a type is unranked or has dynamic dimensions.
+    fn element_count(&self) -> Option<usize> {
+        if self.has_static_shape() {
+            (0..self.rank())
+                .map(|index| self.dim_size(index).ok())
+                .product()
+        } else {
+            None
+        }
+    }
+
+    /// Gets a byte width of an element.
+    ///
+    /// It returns `None` if an element type does not have a fixed size, such
+    /// as `index`. Integers are rounded up to whole bytes.
+    fn element_byte_width(&self) -> Option<usize> {
+        let element = self.element();
+
+        if let Ok(integer) = IntegerType::try_from(element) {
+            Some((integer.width() as usize + 7) / 8)
+        } else if element.is_f64() {
+            Some(8)
+        } else if element.is_f32() {
+            Some(4)
+        } else if element.is_f16() || element.is_bf16() {
+            Some(2)
+        } else if element.is_float8e4m3fn() || element.is_float8e5m2() {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    /// Clones a type with a new shape and optionally a new element type.
+    ///
+    /// Unranked tensors and mem-refs become ranked ones. Encodings of tensors
+    /// and memory spaces of mem-refs are preserved. Layouts of mem-refs are
+    /// preserved only if their ranks do not change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a type is not a tensor, mem-ref, or vector.
+    fn clone_with(&self, element: Option<Type<'c>>, shape: &[i64]) -> Type<'c> {
+        let element = element.unwrap_or_else(|| self.element()).to_raw();
+        let rank = shape.len() as isize;
+
+        unsafe {
+            Type::from_raw(if self.is_ranked_tensor() {
+                mlirRankedTensorTypeGet(
+                    rank,
+                    shape.as_ptr(),
+                    element,
+                    mlirRankedTensorTypeGetEncoding(self.to_raw()),
+                )
+            } else if self.is_unranked_tensor() {
+                mlirRankedTensorTypeGet(rank, shape.as_ptr(), element, mlirAttributeGetNull())
+            } else if self.is_mem_ref() {
+                mlirMemRefTypeGet(
+                    element,
+                    rank,
+                    shape.as_ptr(),
+                    if self.rank() == shape.len() {
+                        mlirMemRefTypeGetLayout(self.to_raw())
+                    } else {
+                        mlirAttributeGetNull()
+                    },
+                    mlirMemRefTypeGetMemorySpace(self.to_raw()),
+                )
+            } else if self.is_unranked_mem_ref() {
+                mlirMemRefTypeGet(
+                    element,
+                    rank,
+                    shape.as_ptr(),
+                    mlirAttributeGetNull(),
+                    mlirUnrankedMemrefGetMemorySpace(self.to_raw()),
+                )
+            } else if self.is_vector() {
+                mlirVectorTypeGet(rank, shape.as_ptr(), element)
+            } else {
+                panic!("shaped type expected: {}", self)
+            })
+        }
+    }
+
+    /// Gets a total byte size of elements.
+    ///
+    /// It returns `None` if either a number of elements or a byte width of an
+    /// element is unknown.
+    fn total_byte_size(&self) -> Option<usize> {
+        Some(self.element_count()? * self.element_byte_width()?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        ir::{r#type::MemRefType, Type},
+        ir::{
+            r#type::{MemRefType, RankedTensorType},
+            Type,
+        },
         Context,
     };
 
@@ -115,4 +219,69 @@ mod tests {
         assert!(MemRefType::new(element_type, &[0], None, None).has_rank(),);
         assert!(MemRefType::new(element_type, &[0, 0], None, None).has_rank(),);
     }
+
+    #[test]
+    fn element_count() {
+        let context = Context::new();
+        let r#type = RankedTensorType::new(&[2, 3], Type::float32(&context), None);
+
+        assert_eq!(r#type.element_count(), Some(6));
+        assert_eq!(r#type.element_byte_width(), Some(4));
+        assert_eq!(r#type.total_byte_size(), Some(24));
+    }
+
+    #[test]
+    fn clone_with() {
+        let context = Context::new();
+        let element_type = Type::float32(&context);
+        let r#type = RankedTensorType::new(&[2, 2], element_type, None);
+
+        assert_eq!(
+            r#type.clone_with(None, &[4, 4]),
+            RankedTensorType::new(&[4, 4], element_type, None).into()
+        );
+        assert_eq!(
+            r#type.clone_with(Some(Type::index(&context)), &[4]),
+            RankedTensorType::new(&[4], Type::index(&context), None).into()
+        );
+    }
+
+    #[test]
+    fn clone_with_mem_ref() {
+        let context = Context::new();
+        let r#type = MemRefType::try_from(
+            Type::parse(&context, "memref<2x2xf32, strided<[2, 1]>, 1>").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            r#type.clone_with(None, &[3, 3]),
+            Type::parse(&context, "memref<3x3xf32, strided<[2, 1]>, 1>").unwrap()
+        );
+        assert_eq!(
+            r#type.clone_with(None, &[4]),
+            Type::parse(&context, "memref<4xf32, 1>").unwrap()
+        );
+    }
+
+    #[test]
+    fn element_count_dynamic() {
+        let context = Context::new();
+        let r#type =
+            MemRefType::try_from(Type::parse(&context, "memref<?x3xi8>").unwrap()).unwrap();
+
+        assert_eq!(r#type.element_count(), None);
+        assert_eq!(r#type.element_byte_width(), Some(1));
+        assert_eq!(r#type.total_byte_size(), None);
+    }
+
+    #[test]
+    fn element_byte_width_index() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::new(Type::index(&context), &[2], None, None).element_byte_width(),
+            None
+        );
+    }
 }
@@ -1,9 +1,10 @@
 use super::TypeId;
-use crate::context::ContextRef;
-use mlir_sys::{mlirTypeDump, mlirTypeGetContext, mlirTypeGetTypeID, MlirType};
+use crate::{context::ContextRef, ir::sealed::Sealed, utility::print_bytes_callback};
+use mlir_sys::{mlirTypeDump, mlirTypeGetContext, mlirTypeGetTypeID, mlirTypePrint, MlirType};
+use std::{ffi::c_void, fmt::Display};
 
 /// Trait for type-like types.
-pub trait TypeLike<'c> {
+pub trait TypeLike<'c>: Display + Sealed {
     /// Converts a type into a raw object.
     fn to_raw(&self) -> MlirType;
 
@@ -22,6 +23,21 @@ pub trait TypeLike<'c> {
         unsafe { mlirTypeDump(self.to_raw()) }
     }
 
+    /// Converts a type into bytes without UTF-8 validation.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+
+        unsafe {
+            mlirTypePrint(
+                self.to_raw(),
+                Some(print_bytes_callback),
+                &mut bytes as *mut _ as *mut c_void,
+            )
+        }
+
+        bytes
+    }
+
     melior_macro::type_check_functions!(
         mlirTypeIsAAnyQuantizedType,
         mlirTypeIsABF16,
@@ -75,6 +91,13 @@ mod tests {
         Type::parse(&Context::new(), "i8").unwrap().context();
     }
 
+    #[test]
+    fn context_of_integer() {
+        let context = Context::new();
+
+        assert!(*Type::from(IntegerType::new(&context, 32)).context() == context);
+    }
+
     #[test]
     fn id() {
         let context = Context::new();
@@ -121,4 +144,22 @@ mod tests {
     fn dump() {
         Type::index(&Context::new()).dump();
     }
+
+    #[test]
+    fn to_bytes() {
+        assert_eq!(IntegerType::new(&Context::new(), 64).to_bytes(), b"i64");
+    }
+
+    #[test]
+    fn to_string() {
+        fn print<'c>(r#type: impl TypeLike<'c>) -> String {
+            r#type.to_string()
+        }
+
+        let context = Context::new();
+
+        assert_eq!(print(Type::index(&context)), "index");
+        assert_eq!(print(IntegerType::new(&context, 64)), "i64");
+        assert_eq!(print(FunctionType::new(&context, &[], &[])), "() -> ()");
+    }
 }
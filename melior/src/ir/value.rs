@@ -1,9 +1,16 @@
 mod value_like;
 
 pub use self::value_like::ValueLike;
-use super::{block::BlockArgument, operation::OperationResult, Type};
-use crate::{utility::print_callback, Context};
-use mlir_sys::{mlirValueEqual, mlirValuePrint, MlirValue};
+use super::{
+    block::BlockArgument,
+    operation::{OperationPrintingFlags, OperationResult},
+    Type,
+};
+use crate::{
+    utility::{print_callback, print_string_callback},
+    Context, Error,
+};
+use mlir_sys::{mlirValueEqual, mlirValuePrint, mlirValuePrintAsOperand, MlirValue};
 use std::{
     ffi::c_void,
     fmt::{self, Debug, Display, Formatter},
@@ -33,6 +40,29 @@ impl<'c, 'a> Value<'c, 'a> {
             _parent: Default::default(),
         }
     }
+
+    /// Converts a value into an operand string, such as `%arg0`.
+    pub fn as_operand_string(&self) -> Result<String, Error> {
+        self.to_string_with_flags(&OperationPrintingFlags::new())
+    }
+
+    /// Converts a value into an operand string with printing flags.
+    pub fn to_string_with_flags(&self, flags: &OperationPrintingFlags) -> Result<String, Error> {
+        let mut data = (String::new(), Ok::<_, Error>(()));
+
+        unsafe {
+            mlirValuePrintAsOperand(
+                self.raw,
+                flags.to_raw(),
+                Some(print_string_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1?;
+
+        Ok(data.0)
+    }
 }
 
 impl<'c, 'a> ValueLike<'c> for Value<'c, 'a> {
@@ -79,7 +109,7 @@ from_borrowed_subtypes!(Value, BlockArgument, OperationResult);
 mod tests {
     use super::*;
     use crate::{
-        ir::{operation::OperationBuilder, Attribute, Block, Identifier, Location},
+        ir::{operation::OperationBuilder, Attribute, Block, Identifier, Location, Module},
         test::create_test_context,
         Context,
     };
@@ -247,4 +277,78 @@ mod tests {
             "Value(\n%c0 = arith.constant 0 : index\n)"
         );
     }
+
+    #[test]
+    fn single_use() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[(Type::index(&context), location)]);
+        let argument = block.argument(0).unwrap();
+
+        assert!(argument.single_use().is_none());
+        assert!(!argument.has_one_use());
+
+        let operation = block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument.into()])
+                .build(),
+        );
+
+        assert_eq!(argument.single_use().unwrap().owner(), operation);
+        assert!(argument.has_one_use());
+    }
+
+    #[test]
+    fn single_use_with_multiple_uses() {
+        let context = create_test_context();
+        context.set_allow_unregistered_dialects(true);
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[(Type::index(&context), location)]);
+        let argument = block.argument(0).unwrap();
+
+        block.append_operation(
+            OperationBuilder::new("foo", location)
+                .add_operands(&[argument.into(), argument.into()])
+                .build(),
+        );
+
+        assert!(argument.single_use().is_none());
+        assert!(!argument.has_one_use());
+    }
+
+    #[test]
+    fn to_string_with_flags() {
+        let context = create_test_context();
+        let module = Module::parse(&context, "func.func @foo(%arg0: index) { return }").unwrap();
+        let function = module.body().first_operation().unwrap();
+        let region = function.region(0).unwrap();
+        let block = region.first_block().unwrap();
+        let argument = Value::from(block.argument(0).unwrap());
+
+        assert_eq!(
+            argument.to_string_with_flags(&OperationPrintingFlags::new()),
+            Ok("%arg0".into())
+        );
+        assert_eq!(
+            argument.to_string_with_flags(&OperationPrintingFlags::new().use_local_scope()),
+            Ok("%arg0".into())
+        );
+    }
+
+    #[test]
+    fn as_operand_string() {
+        let context = create_test_context();
+        let module = Module::parse(&context, "func.func @foo(%arg0: index) { return }").unwrap();
+        let function = module.body().first_operation().unwrap();
+        let region = function.region(0).unwrap();
+        let block = region.first_block().unwrap();
+
+        assert_eq!(
+            Value::from(block.argument(0).unwrap()).as_operand_string(),
+            Ok("%arg0".into())
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use super::Type;
+use crate::ir::operation::OperationOperand;
 use mlir_sys::{
-    mlirValueDump, mlirValueGetType, mlirValueIsABlockArgument, mlirValueIsAOpResult, MlirValue,
+    mlirValueDump, mlirValueGetFirstUse, mlirValueGetType, mlirValueIsABlockArgument,
+    mlirValueIsAOpResult, MlirValue,
 };
 
 /// Trait for value-like types.
@@ -13,6 +15,23 @@ pub trait ValueLike<'c> {
         unsafe { Type::from_raw(mlirValueGetType(self.to_raw())) }
     }
 
+    /// Gets a first use.
+    fn first_use(&self) -> Option<OperationOperand<'c, '_>> {
+        unsafe { OperationOperand::from_option_raw(mlirValueGetFirstUse(self.to_raw())) }
+    }
+
+    /// Gets a use if a value has exactly one use.
+    fn single_use(&self) -> Option<OperationOperand<'c, '_>> {
+        let operand = self.first_use()?;
+
+        operand.next_use().is_none().then_some(operand)
+    }
+
+    /// Returns `true` if a value has exactly one use.
+    fn has_one_use(&self) -> bool {
+        self.single_use().is_some()
+    }
+
     /// Returns `true` if a value is a block argument.
     fn is_block_argument(&self) -> bool {
         unsafe { mlirValueIsABlockArgument(self.to_raw()) }
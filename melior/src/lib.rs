@@ -13,14 +13,16 @@ pub mod ir;
 mod logical_result;
 pub mod pass;
 mod string_ref;
-#[cfg(test)]
-mod test;
+#[cfg(any(test, feature = "test-utilities"))]
+pub mod test;
 pub mod utility;
 
 pub use self::{
     context::{Context, ContextRef},
     error::Error,
-    execution_engine::ExecutionEngine,
+    execution_engine::{
+        ExecutionEngine, InvokeArguments, InvokeResult, InvokeValue, RankedMemRefDescriptor,
+    },
     string_ref::StringRef,
 };
 
@@ -119,7 +121,9 @@ mod tests {
 
             let zero = function_block.append_operation(arith::constant(
                 &context,
-                IntegerAttribute::new(0, Type::index(&context)).into(),
+                IntegerAttribute::new(0, Type::index(&context))
+                    .unwrap()
+                    .into(),
                 location,
             ));
 
@@ -137,7 +141,9 @@ mod tests {
 
             let one = function_block.append_operation(arith::constant(
                 &context,
-                IntegerAttribute::new(1, Type::index(&context)).into(),
+                IntegerAttribute::new(1, Type::index(&context))
+                    .unwrap()
+                    .into(),
                 location,
             ));
 
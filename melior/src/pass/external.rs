@@ -7,10 +7,10 @@ use crate::{
     ContextRef, StringRef,
 };
 use mlir_sys::{
-    mlirCreateExternalPass, MlirContext, MlirExternalPass, MlirExternalPassCallbacks,
-    MlirLogicalResult, MlirOperation,
+    mlirCreateExternalPass, mlirExternalPassSignalFailure, MlirContext, MlirExternalPass,
+    MlirExternalPassCallbacks, MlirLogicalResult, MlirOperation,
 };
-use std::{mem::transmute, ptr::drop_in_place};
+use std::{marker::PhantomData, mem::transmute, ptr::drop_in_place};
 
 unsafe extern "C" fn callback_construct<'a, T: ExternalPass<'a>>(pass: *mut T) {
     pass.as_mut()
@@ -37,12 +37,15 @@ unsafe extern "C" fn callback_initialize<'a, T: ExternalPass<'a>>(
 
 unsafe extern "C" fn callback_run<'a, T: ExternalPass<'a>>(
     op: MlirOperation,
-    _mlir_pass: MlirExternalPass,
+    mlir_pass: MlirExternalPass,
     pass: *mut T,
 ) {
     pass.as_mut()
         .expect("pass should be valid when called")
-        .run(OperationRef::from_raw(op))
+        .run_with_context(
+            OperationRef::from_raw(op),
+            &ExternalPassContext::from_raw(mlir_pass),
+        )
 }
 
 unsafe extern "C" fn callback_clone<'a, T: ExternalPass<'a>>(pass: *mut T) -> *mut T {
@@ -91,6 +94,13 @@ pub trait ExternalPass<'c>: Sized + Clone {
     fn destruct(&mut self) {}
     fn initialize(&mut self, context: ContextRef<'c>);
     fn run(&mut self, operation: OperationRef<'c, '_>);
+
+    /// Runs a pass with a context of its execution.
+    ///
+    /// It calls [`run`](Self::run) by default.
+    fn run_with_context(&mut self, operation: OperationRef<'c, '_>, _: &ExternalPassContext) {
+        self.run(operation)
+    }
 }
 
 impl<'c, F: FnMut(OperationRef<'c, '_>) + Clone> ExternalPass<'c> for F {
@@ -101,6 +111,25 @@ impl<'c, F: FnMut(OperationRef<'c, '_>) + Clone> ExternalPass<'c> for F {
     }
 }
 
+// A function receiving a context of pass execution. It is wrapped as
+// closures without the context already implement `ExternalPass`.
+#[derive(Clone)]
+struct ExternalFn<F>(F);
+
+impl<'c, F: FnMut(OperationRef<'c, '_>, &ExternalPassContext) + Clone> ExternalPass<'c>
+    for ExternalFn<F>
+{
+    fn initialize(&mut self, _context: ContextRef<'c>) {}
+
+    fn run(&mut self, _operation: OperationRef<'c, '_>) {
+        unreachable!("external function passes are run with contexts")
+    }
+
+    fn run_with_context(&mut self, operation: OperationRef<'c, '_>, context: &ExternalPassContext) {
+        (self.0)(operation, context)
+    }
+}
+
 /// Creates a `Pass` object from an external pass
 ///
 /// # Examples
@@ -158,6 +187,66 @@ pub fn create_external<'c, T: ExternalPass<'c>>(
     }
 }
 
+/// A context of an external pass during its execution.
+#[derive(Debug)]
+pub struct ExternalPassContext<'a> {
+    raw: MlirExternalPass,
+    _pass: PhantomData<&'a ()>,
+}
+
+impl<'a> ExternalPassContext<'a> {
+    /// Signals that the pass has failed.
+    pub fn signal_failure(&self) {
+        unsafe { mlirExternalPassSignalFailure(self.raw) }
+    }
+
+    /// Creates an external pass context from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirExternalPass) -> Self {
+        Self {
+            raw,
+            _pass: Default::default(),
+        }
+    }
+
+    /// Converts an external pass context into a raw object.
+    pub const fn to_raw(&self) -> MlirExternalPass {
+        self.raw
+    }
+}
+
+impl Pass {
+    /// Creates a pass from a Rust function run on each operation named
+    /// `op_name`.
+    ///
+    /// An empty `op_name` makes the pass run on any operation. Functions are
+    /// cloned and run on multiple threads when passes are nested.
+    pub fn external<
+        'c,
+        F: FnMut(OperationRef<'c, '_>, &ExternalPassContext) + Clone + Send + 'static,
+    >(
+        pass_id: TypeId,
+        name: &str,
+        argument: &str,
+        description: &str,
+        op_name: &str,
+        run: F,
+    ) -> Self {
+        create_external(
+            ExternalFn(run),
+            pass_id,
+            name,
+            argument,
+            description,
+            op_name,
+            &[],
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,6 +261,10 @@ mod tests {
         test::create_test_context,
         Context,
     };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
 
     #[repr(align(8))]
     struct PassId;
@@ -296,4 +389,60 @@ mod tests {
         ));
         pass_manager.run(&mut module).unwrap();
     }
+
+    #[test]
+    fn external_closure_pass() {
+        static TEST_CLOSURE_PASS: PassId = PassId;
+
+        let context = create_test_context();
+
+        let mut module = create_module(&context);
+        let pass_manager = PassManager::new(&context);
+        let count = Arc::new(AtomicUsize::new(0));
+
+        pass_manager
+            .nested_under("func.func")
+            .add_pass(Pass::external(
+                TypeId::create(&TEST_CLOSURE_PASS),
+                "count functions",
+                "count-functions",
+                "counts functions",
+                "func.func",
+                {
+                    let count = count.clone();
+
+                    move |operation: OperationRef, _: &ExternalPassContext| {
+                        assert_eq!(
+                            operation.name(),
+                            Identifier::new(&operation.context(), "func.func")
+                        );
+                        count.fetch_add(1, Ordering::SeqCst);
+                    }
+                },
+            ));
+        pass_manager.run(&mut module).unwrap();
+
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn external_closure_pass_failure() {
+        static TEST_FAILURE_PASS: PassId = PassId;
+
+        let context = create_test_context();
+
+        let mut module = create_module(&context);
+        let pass_manager = PassManager::new(&context);
+
+        pass_manager.add_pass(Pass::external(
+            TypeId::create(&TEST_FAILURE_PASS),
+            "fail",
+            "fail",
+            "always fails",
+            "",
+            |_: OperationRef, context: &ExternalPassContext| context.signal_failure(),
+        ));
+
+        assert!(pass_manager.run(&mut module).is_err());
+    }
 }
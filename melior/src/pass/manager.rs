@@ -191,6 +191,39 @@ mod tests {
         assert_eq!(manager.run(&mut module), Ok(()));
     }
 
+    #[test]
+    fn run_nested_pipeline() {
+        let context = create_test_context();
+
+        let mut module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo() -> i32 {
+                    %0 = arith.constant 1 : i32
+                    %1 = arith.constant 2 : i32
+                    %2 = arith.addi %0, %1 : i32
+                    return %2 : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+
+        let manager = PassManager::new(&context);
+        let function_manager = manager.nested_under("func.func");
+
+        function_manager.add_pass(pass::transform::create_canonicalizer());
+        function_manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert!(module
+            .as_operation()
+            .to_string()
+            .contains("arith.constant 3 : i32"));
+        assert!(!module.as_operation().to_string().contains("arith.addi"));
+    }
+
     #[test]
     fn print_pass_pipeline() {
         let context = create_test_context();
@@ -209,6 +242,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn print_pass_argument() {
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+
+        manager.add_pass(pass::transform::create_canonicalizer());
+
+        assert!(manager
+            .as_operation_pass_manager()
+            .to_string()
+            .starts_with("builtin.module(canonicalize{"));
+    }
+
     #[test]
     fn parse_pass_pipeline_() {
         let context = Context::new();
@@ -1,9 +1,15 @@
+//! Test utilities.
+//!
+//! They are available to other crates with the `test-utilities` feature.
+
 use crate::{
     dialect::DialectRegistry,
+    ir::{Identifier, Module, Operation},
     utility::{register_all_dialects, register_all_llvm_translations},
     Context,
 };
 
+/// Loads all dialects into a context.
 pub fn load_all_dialects(context: &Context) {
     let registry = DialectRegistry::new();
     register_all_dialects(&registry);
@@ -11,6 +17,8 @@ pub fn load_all_dialects(context: &Context) {
     context.load_all_available_dialects();
 }
 
+/// Creates a context with all dialects and LLVM translations, which prints
+/// diagnostics to standard error.
 pub fn create_test_context() -> Context {
     let context = Context::new();
 
@@ -24,3 +32,86 @@ pub fn create_test_context() -> Context {
 
     context
 }
+
+/// Parses a module.
+///
+/// # Panics
+///
+/// Panics if a source is not a valid module.
+pub fn parse_module<'c>(context: &'c Context, source: &str) -> Module<'c> {
+    Module::parse(context, source).unwrap_or_else(|| panic!("failed to parse module:\n{source}"))
+}
+
+/// Asserts that an operation is printed identically after it is printed and
+/// parsed again.
+///
+/// # Panics
+///
+/// Panics if an operation cannot be parsed back or is printed differently.
+/// Operations failing verification are printed in a generic form and fail to
+/// be parsed back.
+pub fn assert_operation_roundtrips(operation: &Operation) {
+    let context = operation.context();
+    let source = operation.to_string();
+    let module = Module::parse(&context, &source)
+        .unwrap_or_else(|| panic!("failed to parse operation:\n{source}"));
+
+    let printed = if operation.name() == Identifier::new(&context, "builtin.module") {
+        module.as_operation().to_string()
+    } else {
+        module
+            .body()
+            .first_operation()
+            .expect("parsed operation")
+            .to_string()
+    };
+
+    assert_eq!(printed, source, "operation does not round-trip");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{operation::OperationBuilder, Location};
+
+    #[test]
+    fn parse_module() {
+        let context = create_test_context();
+
+        assert!(super::parse_module(&context, "func.func @foo() { return }")
+            .as_operation()
+            .verify());
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to parse module")]
+    fn parse_module_fail() {
+        super::parse_module(&create_test_context(), "func.func @foo(");
+    }
+
+    #[test]
+    fn roundtrip_operation() {
+        let context = create_test_context();
+        let module = super::parse_module(&context, "func.func @foo() { return }");
+
+        assert_operation_roundtrips(&module.body().first_operation().unwrap());
+    }
+
+    #[test]
+    fn roundtrip_module() {
+        let context = create_test_context();
+        let module = super::parse_module(&context, "func.func @foo() { return }");
+
+        assert_operation_roundtrips(&module.as_operation());
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to parse operation")]
+    fn roundtrip_invalid_operation() {
+        let context = create_test_context();
+
+        assert_operation_roundtrips(
+            &OperationBuilder::new("arith.addi", Location::unknown(&context)).build(),
+        );
+    }
+}
@@ -1,16 +1,20 @@
 //! Utility functions.
 
 use crate::{
-    context::Context, dialect::DialectRegistry, logical_result::LogicalResult, pass,
-    string_ref::StringRef, Error,
+    context::Context, diagnostic::CapturedDiagnostic, dialect::DialectRegistry,
+    logical_result::LogicalResult, pass, string_ref::StringRef, Error,
 };
 use mlir_sys::{
-    mlirParsePassPipeline, mlirRegisterAllDialects, mlirRegisterAllLLVMTranslations,
-    mlirRegisterAllPasses, MlirStringRef,
+    mlirBlockGetFirstOperation, mlirBlockGetNextInRegion, mlirOperationGetNextInBlock,
+    mlirParsePassPipeline, mlirRegionGetFirstBlock, mlirRegisterAllDialects,
+    mlirRegisterAllLLVMTranslations, mlirRegisterAllPasses, MlirBlock, MlirOperation, MlirRegion,
+    MlirStringRef,
 };
 use std::{
     ffi::c_void,
     fmt::{self, Formatter},
+    iter::successors,
+    slice,
     sync::Once,
 };
 
@@ -97,9 +101,96 @@ pub(crate) unsafe extern "C" fn print_string_callback(string: MlirStringRef, dat
     })();
 }
 
+pub(crate) unsafe extern "C" fn print_bytes_callback(string: MlirStringRef, data: *mut c_void) {
+    (*(data as *mut Vec<u8>)).extend_from_slice(slice::from_raw_parts(
+        string.data as *const u8,
+        string.length,
+    ));
+}
+
+pub(crate) fn join_diagnostics(diagnostics: &[CapturedDiagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.message())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Wraps lines of textual IR at spaces outside of string literals. Continuation
+// lines are indented further than their original lines. Lines without any
+// break opportunities are left as they are.
+pub(crate) fn wrap_lines(string: &str, width: usize) -> String {
+    let mut wrapped = String::with_capacity(string.len());
+
+    for line in string.split_inclusive('\n') {
+        let (line, newline) = match line.strip_suffix('\n') {
+            Some(line) => (line, "\n"),
+            None => (line, ""),
+        };
+        let indent = line.len() - line.trim_start().len();
+        let continuation = " ".repeat(indent + 4);
+        let mut start = 0;
+        let mut prefix_length = 0;
+        let mut last_break = None;
+        let mut in_string = false;
+        let mut escaped = false;
+
+        for (index, character) in line.char_indices() {
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if character == '\\' {
+                    escaped = true;
+                } else if character == '"' {
+                    in_string = false;
+                }
+            } else if character == '"' {
+                in_string = true;
+            } else if character == ' ' && index > indent && index > start {
+                last_break = Some(index);
+            }
+
+            if prefix_length + index + character.len_utf8() - start > width {
+                if let Some(index) = last_break.take() {
+                    wrapped.push_str(&line[start..index]);
+                    wrapped.push('\n');
+                    wrapped.push_str(&continuation);
+                    start = index + 1;
+                    prefix_length = continuation.len();
+                }
+            }
+        }
+
+        wrapped.push_str(&line[start..]);
+        wrapped.push_str(newline);
+    }
+
+    wrapped
+}
+
+pub(crate) unsafe fn region_blocks(region: MlirRegion) -> Vec<MlirBlock> {
+    successors(
+        Some(mlirRegionGetFirstBlock(region)).filter(|block| !block.ptr.is_null()),
+        |&block| Some(mlirBlockGetNextInRegion(block)).filter(|block| !block.ptr.is_null()),
+    )
+    .collect()
+}
+
+pub(crate) unsafe fn block_operations(block: MlirBlock) -> Vec<MlirOperation> {
+    successors(
+        Some(mlirBlockGetFirstOperation(block)).filter(|operation| !operation.ptr.is_null()),
+        |&operation| {
+            Some(mlirOperationGetNextInBlock(operation))
+                .filter(|operation| !operation.ptr.is_null())
+        },
+    )
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ir::{operation::OperationBuilder, Block, Location, Type};
 
     #[test]
     fn register_dialects() {
@@ -116,6 +207,28 @@ mod tests {
         register_all_dialects(&registry);
     }
 
+    #[test]
+    fn register_dialects_into_context() {
+        let registry = DialectRegistry::new();
+        let context = Context::new();
+
+        register_all_dialects(&registry);
+        context.append_dialect_registry(&registry);
+        context.load_all_available_dialects();
+
+        let location = Location::unknown(&context);
+        let block = Block::new(&[(Type::float32(&context), location)]);
+        let operation = block.append_operation(
+            OperationBuilder::new("math.absf", location)
+                .add_operands(&[block.argument(0).unwrap().into()])
+                .enable_result_type_inference()
+                .build(),
+        );
+
+        assert!(operation.verify());
+        assert!(context.is_registered_operation("math.absf"));
+    }
+
     #[test]
     fn register_llvm_translations() {
         let context = Context::new();
@@ -148,4 +261,25 @@ mod tests {
             register_all_passes();
         }
     }
+
+    #[test]
+    fn wrap_short_lines() {
+        assert_eq!(wrap_lines("foo bar\nbaz\n", 80), "foo bar\nbaz\n");
+    }
+
+    #[test]
+    fn wrap_long_line() {
+        assert_eq!(
+            wrap_lines("  foo bar baz qux\n", 10),
+            "  foo bar\n      baz\n      qux\n"
+        );
+    }
+
+    #[test]
+    fn wrap_line_outside_strings() {
+        assert_eq!(
+            wrap_lines("foo \"bar baz \\\" qux\" quux", 10),
+            "foo\n    \"bar baz \\\" qux\"\n    quux"
+        );
+    }
 }